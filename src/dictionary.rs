@@ -0,0 +1,440 @@
+use crate::{
+    message::{DidLengthSource, Dtc},
+    UdsError, UdsMessage,
+};
+use std::collections::BTreeMap;
+
+/// Identifier of the VIN, read as 17 ASCII characters
+pub const DID_VIN: u16 = 0xf190;
+/// Identifier of the active diagnostic session
+pub const DID_ACTIVE_DIAGNOSTIC_SESSION: u16 = 0xf186;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Encoding of a field of a DID value
+pub enum DidFieldType {
+    /// Unsigned big endian integer, the physical value being
+    /// `raw * scale + offset`
+    Unsigned {
+        /// Physical value of a raw unit
+        scale: f64,
+        /// Physical value of a raw zero
+        offset: f64,
+        /// Unit of the physical value
+        unit: Option<String>,
+    },
+    /// Signed (two's complement) big endian integer, the physical value being
+    /// `raw * scale + offset`
+    Signed {
+        /// Physical value of a raw unit
+        scale: f64,
+        /// Physical value of a raw zero
+        offset: f64,
+        /// Unit of the physical value
+        unit: Option<String>,
+    },
+    /// Unsigned big endian integer with a label per raw value
+    Enum(BTreeMap<u64, String>),
+    /// ASCII string
+    Ascii,
+    /// Opaque bytes
+    Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Named field of a DID value
+pub struct DidField {
+    /// Name of the field
+    pub name: String,
+    /// Length in bytes of the field
+    pub length: usize,
+    /// Encoding of the field
+    pub field_type: DidFieldType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Layout of a DID value, as consecutive fields
+pub struct DidLayout {
+    /// Name of the DID
+    pub name: String,
+    /// Fields of the DID value, in wire order
+    pub fields: Vec<DidField>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Decoded value of a [`DidField`]
+pub enum DidValue {
+    /// Physical value of an `Unsigned` or `Signed` field
+    Number {
+        /// Physical value
+        value: f64,
+        /// Unit of the physical value
+        unit: Option<String>,
+    },
+    /// Value of an `Enum` field, labelled if found in the table
+    Enum {
+        /// Raw value
+        raw: u64,
+        /// Label of the raw value
+        label: Option<String>,
+    },
+    /// Value of an `Ascii` field
+    Text(String),
+    /// Value of a `Bytes` field
+    Bytes(Vec<u8>),
+}
+
+impl DidField {
+    /// Create a field of `length` bytes
+    pub fn new(name: &str, length: usize, field_type: DidFieldType) -> Self {
+        Self {
+            name: name.to_string(),
+            length,
+            field_type,
+        }
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<DidValue, UdsError> {
+        match &self.field_type {
+            DidFieldType::Unsigned {
+                scale,
+                offset,
+                unit,
+            } => Ok(DidValue::Number {
+                value: self.decode_raw(data)? as f64 * scale + offset,
+                unit: unit.clone(),
+            }),
+            DidFieldType::Signed {
+                scale,
+                offset,
+                unit,
+            } => {
+                let raw = self.decode_raw(data)?;
+                let shift = 64 - 8 * data.len() as u32;
+                let raw = ((raw << shift) as i64) >> shift;
+                Ok(DidValue::Number {
+                    value: raw as f64 * scale + offset,
+                    unit: unit.clone(),
+                })
+            }
+            DidFieldType::Enum(table) => {
+                let raw = self.decode_raw(data)?;
+                Ok(DidValue::Enum {
+                    raw,
+                    label: table.get(&raw).cloned(),
+                })
+            }
+            DidFieldType::Ascii => {
+                if !data.is_ascii() {
+                    return Err(UdsError::EncodingError {
+                        msg: format!("{} is not an ASCII string", self.name),
+                    });
+                }
+                Ok(DidValue::Text(String::from_utf8_lossy(data).into_owned()))
+            }
+            DidFieldType::Bytes => Ok(DidValue::Bytes(data.to_vec())),
+        }
+    }
+
+    fn decode_raw(&self, data: &[u8]) -> Result<u64, UdsError> {
+        if !(1..=8).contains(&data.len()) {
+            return Err(UdsError::EncodingError {
+                msg: format!("{} should be 1 to 8 bytes", self.name),
+            });
+        }
+        let mut buf = [0u8; 8];
+        buf[8 - data.len()..].copy_from_slice(data);
+        Ok(u64::from_be_bytes(buf))
+    }
+
+    fn encode(&self, value: &DidValue) -> Result<Vec<u8>, UdsError> {
+        let out_of_range = || UdsError::EncodingError {
+            msg: format!("{} value is out of range", self.name),
+        };
+        let bits = 8 * self.length as u32;
+        let raw = match (&self.field_type, value) {
+            (DidFieldType::Unsigned { scale, offset, .. }, DidValue::Number { value, .. }) => {
+                let raw = ((value - offset) / scale).round();
+                if !raw.is_finite() || raw < 0.0 || raw >= 2f64.powi(bits as i32) {
+                    return Err(out_of_range());
+                }
+                raw as u64
+            }
+            (DidFieldType::Signed { scale, offset, .. }, DidValue::Number { value, .. }) => {
+                let raw = ((value - offset) / scale).round();
+                let half = 2f64.powi(bits as i32 - 1);
+                if !raw.is_finite() || raw < -half || raw >= half {
+                    return Err(out_of_range());
+                }
+                raw as i64 as u64
+            }
+            (DidFieldType::Enum(_), DidValue::Enum { raw, .. }) => {
+                if bits < 64 && *raw >> bits != 0 {
+                    return Err(out_of_range());
+                }
+                *raw
+            }
+            (DidFieldType::Ascii, DidValue::Text(text)) => {
+                if !text.is_ascii() {
+                    return Err(UdsError::EncodingError {
+                        msg: format!("{} is not an ASCII string", self.name),
+                    });
+                }
+                return self.encode_bytes(text.as_bytes());
+            }
+            (DidFieldType::Bytes, DidValue::Bytes(bytes)) => return self.encode_bytes(bytes),
+            _ => {
+                return Err(UdsError::EncodingError {
+                    msg: format!("{} value doesn't match its type", self.name),
+                })
+            }
+        };
+        if !(1..=8).contains(&self.length) {
+            return Err(UdsError::EncodingError {
+                msg: format!("{} should be 1 to 8 bytes", self.name),
+            });
+        }
+        Ok(raw.to_be_bytes()[8 - self.length..].to_vec())
+    }
+
+    fn encode_bytes(&self, bytes: &[u8]) -> Result<Vec<u8>, UdsError> {
+        if bytes.len() != self.length {
+            return Err(UdsError::EncodingError {
+                msg: format!("{} should be {} bytes", self.name, self.length),
+            });
+        }
+        Ok(bytes.to_vec())
+    }
+}
+
+impl DidLayout {
+    /// Create a layout from its fields
+    pub fn new(name: &str, fields: Vec<DidField>) -> Self {
+        Self {
+            name: name.to_string(),
+            fields,
+        }
+    }
+
+    /// Length in bytes of the DID value
+    pub fn length(&self) -> usize {
+        self.fields.iter().map(|field| field.length).sum()
+    }
+}
+
+/// Layouts of DIDs, to decode and encode DID values as named typed values
+///
+/// It provides the DID lengths needed by [`ReadDIDRsp::records`](crate::message::ReadDIDRsp::records).
+///
+/// Example:
+/// ```
+/// use uds_rw::{DidDictionary, DidField, DidFieldType, DidLayout, DidValue};
+///
+/// let mut dictionary = DidDictionary::iso14229();
+/// dictionary.insert(
+///     0x0100,
+///     DidLayout::new(
+///         "VehicleSpeed",
+///         vec![DidField::new(
+///             "speed",
+///             2,
+///             DidFieldType::Unsigned {
+///                 scale: 0.01,
+///                 offset: 0.0,
+///                 unit: Some("km/h".to_string()),
+///             },
+///         )],
+///     ),
+/// );
+/// let values = dictionary.decode(0x0100, &[0x13, 0x88]).unwrap();
+/// assert_eq!(
+///     values,
+///     vec![(
+///         "speed".to_string(),
+///         DidValue::Number { value: 50.0, unit: Some("km/h".to_string()) }
+///     )]
+/// );
+/// assert_eq!(dictionary.encode(0x0100, &values).unwrap(), vec![0x13, 0x88]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DidDictionary {
+    layouts: BTreeMap<u16, DidLayout>,
+}
+
+impl DidDictionary {
+    /// Create an empty dictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a dictionary with the ISO 14229 identifiers of fixed layout
+    pub fn iso14229() -> Self {
+        let mut dictionary = Self::new();
+        dictionary.insert(
+            DID_VIN,
+            DidLayout::new("VIN", vec![DidField::new("vin", 17, DidFieldType::Ascii)]),
+        );
+        let sessions = [
+            (1, "DefaultSession"),
+            (2, "ProgrammingSession"),
+            (3, "ExtendedDiagnosticSession"),
+            (4, "SafetySystemDiagnosticSession"),
+        ];
+        dictionary.insert(
+            DID_ACTIVE_DIAGNOSTIC_SESSION,
+            DidLayout::new(
+                "ActiveDiagnosticSession",
+                vec![DidField::new(
+                    "session",
+                    1,
+                    DidFieldType::Enum(
+                        sessions
+                            .into_iter()
+                            .map(|(raw, label)| (raw, label.to_string()))
+                            .collect(),
+                    ),
+                )],
+            ),
+        );
+        dictionary
+    }
+
+    /// Insert the layout of `did`, returning the layout it replaces
+    pub fn insert(&mut self, did: u16, layout: DidLayout) -> Option<DidLayout> {
+        self.layouts.insert(did, layout)
+    }
+
+    /// Layout of `did`
+    pub fn get(&self, did: u16) -> Option<&DidLayout> {
+        self.layouts.get(&did)
+    }
+
+    /// Decode the value of `did` into its named fields
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if `did` is unknown or `data`
+    /// doesn't match its layout, [`UdsError::PayloadLengthTooShort`] if
+    /// `data` doesn't have the layout length.
+    pub fn decode(&self, did: u16, data: &[u8]) -> Result<Vec<(String, DidValue)>, UdsError> {
+        let layout = self.layout(did)?;
+        if data.len() != layout.length() {
+            return Err(UdsError::PayloadLengthTooShort {
+                value: data.len() as u32,
+                expected: layout.length() as u32,
+            });
+        }
+        let mut rest = data;
+        let mut values = vec![];
+        for field in &layout.fields {
+            let (field_data, others) = rest.split_at(field.length);
+            values.push((field.name.clone(), field.decode(field_data)?));
+            rest = others;
+        }
+        Ok(values)
+    }
+
+    /// Encode the named field `values` of `did`, in any order
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if `did` is unknown, a field is
+    /// missing, or a value doesn't fit its field.
+    pub fn encode(&self, did: u16, values: &[(String, DidValue)]) -> Result<Vec<u8>, UdsError> {
+        let layout = self.layout(did)?;
+        let mut data = Vec::with_capacity(layout.length());
+        for field in &layout.fields {
+            let Some((_, value)) = values.iter().find(|(name, _)| *name == field.name) else {
+                return Err(UdsError::EncodingError {
+                    msg: format!("Missing field {} of {}", field.name, layout.name),
+                });
+            };
+            data.extend(field.encode(value)?);
+        }
+        Ok(data)
+    }
+
+    fn layout(&self, did: u16) -> Result<&DidLayout, UdsError> {
+        self.get(did).ok_or_else(|| UdsError::EncodingError {
+            msg: format!("Unknown DID 0x{did:04x}"),
+        })
+    }
+}
+
+impl DidLengthSource for DidDictionary {
+    fn did_length(&self, did: u16) -> Option<usize> {
+        self.get(did).map(DidLayout::length)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Symbolic definition of a DTC
+pub struct DtcDefinition {
+    /// Short name of the DTC
+    pub name: String,
+    /// Code displayed to the user, e.g. `P0A1B`
+    pub display_code: Option<String>,
+    /// Description of the DTC
+    pub text: Option<String>,
+}
+
+/// Symbolic definitions of DTCs
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DtcDictionary {
+    definitions: BTreeMap<u32, DtcDefinition>,
+}
+
+impl DtcDictionary {
+    /// Create an empty dictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert the definition of `dtc`, returning the definition it replaces
+    pub fn insert(&mut self, dtc: &Dtc, definition: DtcDefinition) -> Option<DtcDefinition> {
+        self.definitions.insert(dtc.into(), definition)
+    }
+
+    /// Definition of `dtc`
+    pub fn get(&self, dtc: &Dtc) -> Option<&DtcDefinition> {
+        self.definitions.get(&dtc.into())
+    }
+}
+
+/// Display of a [`UdsMessage`] with symbolic DID and DTC names
+///
+/// DIDs known to the [`DidDictionary`] are printed as their named fields, and
+/// DTCs known to the [`DtcDictionary`] with their name. Other messages are
+/// printed as their plain display.
+///
+/// Example:
+/// ```
+/// use uds_rw::{message, DidDictionary, DtcDictionary, SymbolicMessage, UdsMessage};
+///
+/// let message = UdsMessage::ReadDIDRsp(message::ReadDIDRsp {
+///     did: 0xf190,
+///     user_data: b"WVWZZZ1JZXW000001".to_vec(),
+///     other_records: vec![],
+/// });
+/// let dids = DidDictionary::iso14229();
+/// let dtcs = DtcDictionary::new();
+/// assert_eq!(
+///     SymbolicMessage::new(&message, &dids, &dtcs).to_string(),
+///     "ReadDIDRsp(VIN(vin=\"WVWZZZ1JZXW000001\"))"
+/// );
+/// ```
+pub struct SymbolicMessage<'a> {
+    pub(crate) message: &'a UdsMessage,
+    pub(crate) dids: &'a DidDictionary,
+    pub(crate) dtcs: &'a DtcDictionary,
+}
+
+impl<'a> SymbolicMessage<'a> {
+    /// Display `message` with the names of `dids` and `dtcs`
+    pub fn new(message: &'a UdsMessage, dids: &'a DidDictionary, dtcs: &'a DtcDictionary) -> Self {
+        Self {
+            message,
+            dids,
+            dtcs,
+        }
+    }
+}
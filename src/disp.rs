@@ -4,28 +4,62 @@ use std::fmt::{self, Display, Formatter};
 use message::NrcCode;
 
 use crate::message;
+use crate::DidValue;
 use crate::UdsMessage;
 
 mod dtc;
+mod symbolic;
 
 pub fn fmt(uds: &UdsMessage, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
     match uds {
         UdsMessage::Nrc(d) => d.fmt(f),
         UdsMessage::RawUds(d) => d.fmt(f),
+        UdsMessage::ClearDTCReq(d) => d.fmt(f),
+        UdsMessage::ClearDTCRsp(d) => d.fmt(f),
+        UdsMessage::CommunicationControlReq(d) => d.fmt(f),
+        UdsMessage::CommunicationControlRsp(d) => d.fmt(f),
+        UdsMessage::ControlDTCSettingReq(d) => d.fmt(f),
+        UdsMessage::ControlDTCSettingRsp(d) => d.fmt(f),
+        UdsMessage::DiagnosticSessionControlReq(d) => d.fmt(f),
+        UdsMessage::DiagnosticSessionControlRsp(d) => d.fmt(f),
+        UdsMessage::DynamicallyDefineDIDReq(d) => d.fmt(f),
+        UdsMessage::DynamicallyDefineDIDRsp(d) => d.fmt(f),
+        UdsMessage::EcuResetReq(d) => d.fmt(f),
+        UdsMessage::EcuResetRsp(d) => d.fmt(f),
+        UdsMessage::IoControlReq(d) => d.fmt(f),
+        UdsMessage::IoControlRsp(d) => d.fmt(f),
+        UdsMessage::LinkControlReq(d) => d.fmt(f),
+        UdsMessage::LinkControlRsp(d) => d.fmt(f),
         UdsMessage::ReadDIDReq(d) => d.fmt(f),
         UdsMessage::ReadDIDRsp(d) => d.fmt(f),
         UdsMessage::ReadDTCReq(d) => d.fmt(f),
         UdsMessage::ReadDTCRsp(d) => d.fmt(f),
-        UdsMessage::RequestDownloadReq(d) => d.fmt(f),
-        UdsMessage::RequestDownloadRsp(d) => d.fmt(f),
+        UdsMessage::ReadMemoryByAddressReq(d) => d.fmt(f),
+        UdsMessage::ReadMemoryByAddressRsp(d) => d.fmt(f),
+        UdsMessage::ReadPeriodicDIDReq(d) => d.fmt(f),
+        UdsMessage::ReadPeriodicDIDRsp(d) => d.fmt(f),
+        UdsMessage::RequestDownloadReq(d) => fmt_transfer_request(f, "RequestDownloadReq", d),
+        UdsMessage::RequestDownloadRsp(d) => fmt_transfer_response(f, "RequestDownloadRsp", d),
         UdsMessage::RequestFileTransferReq(d) => d.fmt(f),
         UdsMessage::RequestFileTransferRsp(d) => d.fmt(f),
+        UdsMessage::RequestUploadReq(d) => fmt_transfer_request(f, "RequestUploadReq", d),
+        UdsMessage::RequestUploadRsp(d) => fmt_transfer_response(f, "RequestUploadRsp", d),
+        UdsMessage::ResponseOnEventReq(d) => d.fmt(f),
+        UdsMessage::ResponseOnEventRsp(d) => d.fmt(f),
+        UdsMessage::RoutineControlReq(d) => d.fmt(f),
+        UdsMessage::RoutineControlRsp(d) => d.fmt(f),
+        UdsMessage::SecurityAccessReq(d) => d.fmt(f),
+        UdsMessage::SecurityAccessRsp(d) => d.fmt(f),
+        UdsMessage::TesterPresentReq(d) => d.fmt(f),
+        UdsMessage::TesterPresentRsp(d) => d.fmt(f),
         UdsMessage::TransferDataReq(d) => d.fmt(f),
         UdsMessage::TransferDataRsp(d) => d.fmt(f),
         UdsMessage::TransferExitReq(d) => d.fmt(f),
         UdsMessage::TransferExitRsp(d) => d.fmt(f),
         UdsMessage::WriteDIDReq(d) => d.fmt(f),
         UdsMessage::WriteDIDRsp(d) => d.fmt(f),
+        UdsMessage::WriteMemoryByAddressReq(d) => d.fmt(f),
+        UdsMessage::WriteMemoryByAddressRsp(d) => d.fmt(f),
     }
 }
 
@@ -44,9 +78,228 @@ impl Display for message::RawUds {
     }
 }
 
+impl Display for message::ClearDTCReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.memory {
+            Some(memory) => write!(
+                f,
+                "ClearDTCReq(group={}, memory=0x{memory:02x})",
+                self.group
+            ),
+            None => write!(f, "ClearDTCReq(group={})", self.group),
+        }
+    }
+}
+
+impl Display for message::ClearDTCRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ClearDTCRsp()")
+    }
+}
+
+impl Display for message::CommunicationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let messages = match (self.normal_messages, self.network_management_messages) {
+            (true, true) => "normal+network_management",
+            (true, false) => "normal",
+            (false, true) => "network_management",
+            (false, false) => "none",
+        };
+        match self.subnet {
+            message::CommunicationSubnet::AllNetworks => write!(f, "{messages} on all networks"),
+            message::CommunicationSubnet::Network(n) => write!(f, "{messages} on subnet {n}"),
+            message::CommunicationSubnet::ReceivingNetwork => {
+                write!(f, "{messages} on receiving network")
+            }
+        }
+    }
+}
+
+impl Display for message::CommunicationControlReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CommunicationControlReq(control={:?}, type={}",
+            self.control_type, self.communication_type
+        )?;
+        if let Some(node) = self.node_identification_number {
+            write!(f, ", node=0x{node:04x}")?;
+        }
+        write!(f, ", suppress_pos_rsp={})", self.suppress_positive_response)
+    }
+}
+
+impl Display for message::CommunicationControlRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CommunicationControlRsp(control={:?})",
+            self.control_type
+        )
+    }
+}
+
+impl Display for message::ControlDTCSettingReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ControlDTCSettingReq(setting={:?}", self.setting_type)?;
+        match &self.option {
+            message::DtcSettingOption::None => {}
+            message::DtcSettingOption::Dtcs(dtcs) => {
+                for dtc in dtcs {
+                    write!(f, ", {dtc}")?;
+                }
+            }
+            message::DtcSettingOption::Raw(record) => write!(f, ", record={record:02x?}")?,
+        }
+        write!(f, ", suppress_pos_rsp={})", self.suppress_positive_response)
+    }
+}
+
+impl Display for message::ControlDTCSettingRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ControlDTCSettingRsp(setting={:?})", self.setting_type)
+    }
+}
+
+impl Display for message::DiagnosticSessionControlReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DiagnosticSessionControlReq(session={:?}, suppress_pos_rsp={})",
+            self.session_type, self.suppress_positive_response
+        )
+    }
+}
+
+impl Display for message::DiagnosticSessionControlRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DiagnosticSessionControlRsp(session={:?}, p2={}ms, p2*={}ms)",
+            self.session_type,
+            self.p2_server_max.as_millis(),
+            self.p2_star_server_max.as_millis()
+        )
+    }
+}
+
+impl Display for message::DynamicallyDefineDIDReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.definition {
+            message::DynamicDidDefinition::DefineByIdentifier { did, sources } => {
+                write!(f, "DynamicallyDefineDIDReq(did=0x{did:04x}")?;
+                for source in sources {
+                    write!(
+                        f,
+                        ", 0x{:04x}(position={}, size={})",
+                        source.source_did, source.position, source.memory_size
+                    )?;
+                }
+                write!(f, ")")
+            }
+            message::DynamicDidDefinition::DefineByMemoryAddress { did, sources, .. } => {
+                write!(f, "DynamicallyDefineDIDReq(did=0x{did:04x}")?;
+                for source in sources {
+                    write!(
+                        f,
+                        ", address=0x{:x} size=0x{:x}",
+                        source.memory_address, source.memory_size
+                    )?;
+                }
+                write!(f, ")")
+            }
+            message::DynamicDidDefinition::ClearDynamicallyDefinedDataIdentifier {
+                did: Some(did),
+            } => write!(f, "DynamicallyDefineDIDReq(clear did=0x{did:04x})"),
+            message::DynamicDidDefinition::ClearDynamicallyDefinedDataIdentifier { did: None } => {
+                write!(f, "DynamicallyDefineDIDReq(clear all)")
+            }
+        }
+    }
+}
+
+impl Display for message::DynamicallyDefineDIDRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.did {
+            Some(did) => write!(
+                f,
+                "DynamicallyDefineDIDRsp({:?}, did=0x{did:04x})",
+                self.definition_type
+            ),
+            None => write!(f, "DynamicallyDefineDIDRsp({:?})", self.definition_type),
+        }
+    }
+}
+
+impl Display for message::EcuResetReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EcuResetReq(reset={:?}, suppress_pos_rsp={})",
+            self.reset_type, self.suppress_positive_response
+        )
+    }
+}
+
+impl Display for message::EcuResetRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.power_down_time {
+            Some(time) => write!(
+                f,
+                "EcuResetRsp(reset={:?}, power_down_time={time}s)",
+                self.reset_type
+            ),
+            None => write!(f, "EcuResetRsp(reset={:?})", self.reset_type),
+        }
+    }
+}
+
+impl Display for message::IoControlReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "IoControlReq(did=0x{:04x}, {:?}, state={:02x?}",
+            self.did, self.parameter, self.control_state
+        )?;
+        if let Some(mask) = &self.control_enable_mask {
+            write!(f, ", mask={mask:02x?}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for message::IoControlRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "IoControlRsp(did=0x{:04x}, {:?}, state={:02x?})",
+            self.did, self.parameter, self.control_state
+        )
+    }
+}
+
+impl Display for message::LinkControlReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.baudrate {
+            Some(baudrate) => write!(
+                f,
+                "LinkControlReq(control={:?}, baudrate={baudrate:?})",
+                self.control_type
+            ),
+            None => write!(f, "LinkControlReq(control={:?})", self.control_type),
+        }
+    }
+}
+
+impl Display for message::LinkControlRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "LinkControlRsp(control={:?})", self.control_type)
+    }
+}
+
 impl Display for message::ReadDIDReq {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "ReadDIDReq(did=0x{:2x})", self.did)
+        write!(f, "ReadDIDReq(dids={:04x?})", self.dids)
     }
 }
 
@@ -57,7 +310,15 @@ impl Display for message::ReadDIDRsp {
             "ReadDIDRsp(did=0x{:02x}):\n{}",
             self.did,
             indent_str(&pretty_hex(&self.user_data), 4)
-        )
+        )?;
+        for (did, value) in &self.other_records {
+            write!(
+                f,
+                "\n(did=0x{did:02x}):\n{}",
+                indent_str(&pretty_hex(value), 4)
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -73,26 +334,69 @@ impl Display for message::ReadDTCRsp {
     }
 }
 
-impl Display for message::RequestDownloadReq {
+impl Display for message::ReadPeriodicDIDReq {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "RequestDownloadReq(compress=0x{:1x},_encrypt=0x{:1x}, address=0x{:x}, size=0x{:x})",
-            self.compression_method, self.encryption_method, self.memory_address, self.memory_size
+            "ReadPeriodicDIDReq({:?}, periodic_ids={:02x?})",
+            self.transmission_mode, self.periodic_ids
         )
     }
 }
 
-impl Display for message::RequestDownloadRsp {
+impl Display for message::ReadPeriodicDIDRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ReadPeriodicDIDRsp")?;
+        if let Some(frame) = &self.frame {
+            write!(f, ": {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for message::PeriodicDataFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "RequestDownloadRsp() -> max_block_size={}",
-            self.max_block_size
+            "PeriodicDataFrame(did=0x{:04x}):\n{}",
+            self.did(),
+            indent_str(&pretty_hex(&self.data), 4),
         )
     }
 }
 
+fn fmt_transfer_request(
+    f: &mut Formatter<'_>,
+    name: &str,
+    req: &message::TransferRequest,
+) -> fmt::Result {
+    write!(
+        f,
+        "{name}(compress=0x{:1x},_encrypt=0x{:1x}, address=0x{:x}, size=0x{:x})",
+        req.compression_method, req.encryption_method, req.memory_address, req.memory_size
+    )
+}
+
+fn fmt_transfer_response(
+    f: &mut Formatter<'_>,
+    name: &str,
+    rsp: &message::TransferResponse,
+) -> fmt::Result {
+    write!(f, "{name}() -> max_block_size={}", rsp.max_block_size)
+}
+
+impl Display for message::TransferRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_transfer_request(f, "TransferRequest", self)
+    }
+}
+
+impl Display for message::TransferResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_transfer_response(f, "TransferResponse", self)
+    }
+}
+
 impl Display for message::RequestFileTransferReq {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -131,6 +435,157 @@ impl Display for message::RequestFileTransferRsp {
     }
 }
 
+impl Display for message::RoeEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            message::RoeEvent::OnDTCStatusChange { mask } => {
+                write!(f, "OnDTCStatusChange(mask={mask})")
+            }
+            message::RoeEvent::OnTimerInterrupt { timer_rate } => {
+                write!(f, "OnTimerInterrupt(rate=0x{timer_rate:02x})")
+            }
+            message::RoeEvent::OnChangeOfDataIdentifier { did } => {
+                write!(f, "OnChangeOfDataIdentifier(did=0x{did:04x})")
+            }
+            message::RoeEvent::OnComparisonOfValues(comparison) => write!(
+                f,
+                "OnComparisonOfValues(did=0x{:04x}, {:?} 0x{:x})",
+                comparison.did, comparison.logic, comparison.reference
+            ),
+            message::RoeEvent::Reserved { event_type, record } => {
+                write!(f, "Reserved(0x{event_type:02x}, {record:02x?})")
+            }
+            event => write!(f, "{event:?}"),
+        }
+    }
+}
+
+impl Display for message::ResponseOnEventReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ResponseOnEventReq({}", self.event)?;
+        if let Some(window) = self.event_window_time {
+            write!(f, ", window=0x{window:02x}")?;
+        }
+        if let Some(service) = &self.service_to_respond_to {
+            write!(f, ", service={service}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for message::ResponseOnEventRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ResponseOnEventRsp({}, events={}",
+            self.event, self.number_of_events
+        )?;
+        if let Some(window) = self.event_window_time {
+            write!(f, ", window=0x{window:02x}")?;
+        }
+        if let Some(service) = &self.service_to_respond_to {
+            write!(f, ", service={service}")?;
+        }
+        if !self.activated_events.is_empty() {
+            write!(f, ", {:02x?}", self.activated_events)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for message::RoutineControlReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RoutineControlReq(control={:?}, routine=0x{:04x}, option={:02x?}, suppress_pos_rsp={})",
+            self.control_type,
+            self.routine_identifier,
+            self.option_record,
+            self.suppress_positive_response
+        )
+    }
+}
+
+impl Display for message::RoutineControlRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RoutineControlRsp(control={:?}, routine=0x{:04x}",
+            self.control_type, self.routine_identifier
+        )?;
+        if let Some(info) = self.routine_info {
+            write!(f, ", info=0x{info:02x}, status={:02x?}", self.status_record)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for message::ReadMemoryByAddressReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ReadMemoryByAddressReq(address=0x{:x}, size=0x{:x})",
+            self.memory_address, self.memory_size
+        )
+    }
+}
+
+impl Display for message::ReadMemoryByAddressRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ReadMemoryByAddressRsp({:02x?})", self.data)
+    }
+}
+
+impl Display for message::SecurityAccessReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.request {
+            message::SecurityAccessRequest::RequestSeed(data) => write!(
+                f,
+                "SecurityAccessReq(level={}, request_seed, {data:02x?})",
+                self.level
+            ),
+            message::SecurityAccessRequest::SendKey(key) => write!(
+                f,
+                "SecurityAccessReq(level={}, send_key, {key:02x?})",
+                self.level
+            ),
+        }
+    }
+}
+
+impl Display for message::SecurityAccessRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.response {
+            message::SecurityAccessResponse::Seed(seed) => {
+                write!(
+                    f,
+                    "SecurityAccessRsp(level={}, seed={seed:02x?})",
+                    self.level
+                )
+            }
+            message::SecurityAccessResponse::KeyAccepted => {
+                write!(f, "SecurityAccessRsp(level={}, key accepted)", self.level)
+            }
+        }
+    }
+}
+
+impl Display for message::TesterPresentReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TesterPresentReq(suppress_pos_rsp={})",
+            self.suppress_positive_response
+        )
+    }
+}
+
+impl Display for message::TesterPresentRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "TesterPresentRsp()")
+    }
+}
+
 impl Display for message::TransferDataReq {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -143,7 +598,11 @@ impl Display for message::TransferDataReq {
 
 impl Display for message::TransferDataRsp {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "TransferDataRsp(seq={})", self.block_sequence_counter)
+        write!(
+            f,
+            "TransferDataRsp(seq={}, {:02x?})",
+            self.block_sequence_counter, self.data
+        )
     }
 }
 
@@ -176,6 +635,45 @@ impl Display for message::WriteDIDRsp {
     }
 }
 
+impl Display for message::WriteMemoryByAddressReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "WriteMemoryByAddressReq(address=0x{:x}, size=0x{:x}, {:02x?})",
+            self.memory_address, self.memory_size, self.data
+        )
+    }
+}
+
+impl Display for message::WriteMemoryByAddressRsp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "WriteMemoryByAddressRsp(address=0x{:x}, size=0x{:x})",
+            self.memory_address, self.memory_size
+        )
+    }
+}
+
+impl Display for DidValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DidValue::Number { value, unit: None } => write!(f, "{value}"),
+            DidValue::Number {
+                value,
+                unit: Some(unit),
+            } => write!(f, "{value} {unit}"),
+            DidValue::Enum { raw, label: None } => write!(f, "0x{raw:02x}"),
+            DidValue::Enum {
+                raw,
+                label: Some(label),
+            } => write!(f, "{label} (0x{raw:02x})"),
+            DidValue::Text(text) => write!(f, "{text:?}"),
+            DidValue::Bytes(bytes) => write!(f, "{bytes:02x?}"),
+        }
+    }
+}
+
 fn indent_str(astr: &str, indent: usize) -> String {
     let indenter = (1..indent).map(|_| " ").collect::<String>();
     let mut out: String = astr.lines().map(|l| indenter.clone() + l + "\n").collect();
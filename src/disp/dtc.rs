@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::fmt::{Display, LowerHex};
 
 use crate::proto::dtc::*;
 
@@ -75,12 +75,18 @@ impl Display for DTCReqSubfunction {
             ReportUserDefMemoryDTCExtDataRecordByDTCNumber(r) => {
                 write!(f, "ReportUserDefMemoryDTCExtDataRecordByDTCNumber({r})")
             }
+            ReportSupportedDTCExtDataRecord(r) => {
+                write!(f, "ReportSupportedDTCExtDataRecord({r})")
+            }
             ReportWWHOBDDTCByMaskRecord(r) => {
                 write!(f, "ReportWWHOBDDTCByMaskRecord({r})")
             }
             ReportWWHOBDDTCWithPermanentStatus(r) => {
                 write!(f, "ReportWWHOBDDTCWithPermanentStatus({r})")
             }
+            ReportDTCInformationByDTCReadinessGroupIdentifier(r) => {
+                write!(f, "ReportDTCInformationByDTCReadinessGroupIdentifier({r})")
+            }
             _ => Ok(()),
         }
     }
@@ -96,6 +102,46 @@ impl Display for Dtc {
     }
 }
 
+impl Display for DtcDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.format {
+            DtcFormat::Iso15031 | DtcFormat::SaeJ2012WwhObd => {
+                write!(f, "{}", self.dtc.to_j2012())
+            }
+            DtcFormat::SaeJ1939 => write!(f, "SPN {} FMI {}", self.dtc.spn(), self.dtc.fmi()),
+            _ => self.dtc.fmt(f),
+        }
+    }
+}
+
+/// Display of a flags byte as the list of its set flags, and hexadecimal
+/// display of its raw bits
+macro_rules! display_flags {
+    ($($name:ident),*) => {$(
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "[")?;
+                let mut flags = Self::FLAGS.iter().filter(|(flag, _)| self.contains(*flag));
+                if let Some((_, name)) = flags.next() {
+                    write!(f, "{name}")?;
+                }
+                for (_, name) in flags {
+                    write!(f, ", {name}")?;
+                }
+                write!(f, "]")
+            }
+        }
+
+        impl LowerHex for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                LowerHex::fmt(&self.bits(), f)
+            }
+        }
+    )*};
+}
+
+display_flags!(DtcStatus, DtcSeverity);
+
 impl Display for ByDTCStatusMask {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "mask={:02x}", self.mask)
@@ -116,7 +162,21 @@ impl Display for ByDTCMaskRecordRecordNumber {
 
 impl Display for ByDTCSeverityMaskRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "mask=0x{:02x}", self.mask)
+        write!(
+            f,
+            "severity_mask={}, status_mask={}",
+            self.severity_mask, self.status_mask
+        )
+    }
+}
+
+impl Display for ReportWWHOBDDTCByMaskRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "group=0x{:02x}, status_mask={}, severity_mask={}",
+            self.group, self.status_mask, self.severity_mask
+        )
     }
 }
 
@@ -126,6 +186,16 @@ impl Display for ByFunctionalGroupIdentifier {
     }
 }
 
+impl Display for ReportDTCInformationByDTCReadinessGroupIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "group=0x{:02x}, readiness_group=0x{:02x}",
+            self.group, self.readiness_group
+        )
+    }
+}
+
 impl Display for ReportDTCStoredDataByRecordNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "record=0x{:02x}", self.record)
@@ -240,12 +310,21 @@ impl Display for DTCRspSubfunction {
             ResponseUserDefMemoryDTCExtDataRecordByDTCNumber(r) => {
                 write!(f, "ResponseUserDefMemoryDTCExtDataRecordByDTCNumber({r:?})")
             }
+            ResponseSupportedDTCExtDataRecord(r) => {
+                write!(f, "ResponseSupportedDTCExtDataRecord({r:?})")
+            }
             ResponseWWHOBDDTCByMaskRecord(r) => {
                 write!(f, "ResponseWWHOBDDTCByMaskRecord({r:?})")
             }
             ResponseWWHOBDDTCWithPermanentStatus(r) => {
                 write!(f, "ResponseWWHOBDDTCWithPermanentStatus({r:?})")
             }
+            ResponseDTCInformationByDTCReadinessGroupIdentifier(r) => {
+                write!(
+                    f,
+                    "ResponseDTCInformationByDTCReadinessGroupIdentifier({r:?})"
+                )
+            }
             _ => todo!(),
         }
     }
@@ -253,22 +332,24 @@ impl Display for DTCRspSubfunction {
 
 impl Display for DtcAndStatusRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "dtc {} status=0x{:02x}", self.dtc, self.status)
+        write!(
+            f,
+            "dtc {} status=0x{:02x} {}",
+            self.dtc, self.status, self.status
+        )
     }
 }
 
 impl Display for GotListDtcAndStatusRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "avail_mask=0x{:02x} :", self.availability_mask)?;
-        let mut idx = 1;
         let total = self.dtcs.len();
-        for dtc_and_status in &self.dtcs {
+        for (idx, dtc_and_status) in (1..).zip(self.dtcs.iter()) {
             if idx == total {
                 write!(f, "\t{idx:>3}. {dtc_and_status}")?;
             } else {
                 writeln!(f, "\t{idx:>3}. {dtc_and_status}")?;
             }
-            idx += 1;
         }
         Ok(())
     }
@@ -0,0 +1,118 @@
+use crate::message::{DTCRspSubfunction, Dtc, GotListDtcAndStatusRecord};
+use crate::{SymbolicMessage, UdsMessage};
+use std::fmt::{self, Display, Formatter};
+
+impl Display for SymbolicMessage<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.message {
+            UdsMessage::ClearDTCReq(req) => {
+                write!(f, "ClearDTCReq(group=")?;
+                self.fmt_dtc(f, &req.group)?;
+                match req.memory {
+                    Some(memory) => write!(f, ", memory=0x{memory:02x})"),
+                    None => write!(f, ")"),
+                }
+            }
+            UdsMessage::ReadDIDReq(req) => {
+                write!(f, "ReadDIDReq(")?;
+                for (idx, did) in req.dids.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    self.fmt_did_name(f, *did)?;
+                }
+                write!(f, ")")
+            }
+            UdsMessage::ReadDIDRsp(rsp) => match rsp.records(self.dids) {
+                Ok(records) => {
+                    write!(f, "ReadDIDRsp(")?;
+                    for (idx, (did, data)) in records.iter().enumerate() {
+                        if idx > 0 {
+                            write!(f, ", ")?;
+                        }
+                        self.fmt_did_value(f, *did, data)?;
+                    }
+                    write!(f, ")")
+                }
+                Err(_) => rsp.fmt(f),
+            },
+            UdsMessage::ReadDTCRsp(rsp) => match dtc_list(&rsp.sub) {
+                Some((name, list)) => {
+                    write!(
+                        f,
+                        "ReadDTCRsp::{name}: avail_mask=0x{:02x} :",
+                        list.availability_mask
+                    )?;
+                    for (idx, record) in (1..).zip(list.dtcs.iter()) {
+                        write!(f, "\n\t{idx:>3}. dtc ")?;
+                        self.fmt_dtc(f, &record.dtc)?;
+                        write!(f, " status=0x{:02x} {}", record.status, record.status)?;
+                    }
+                    Ok(())
+                }
+                None => rsp.fmt(f),
+            },
+            UdsMessage::WriteDIDReq(req) => {
+                write!(f, "WriteDIDReq(")?;
+                self.fmt_did_value(f, req.did, &req.user_data)?;
+                write!(f, ")")
+            }
+            UdsMessage::WriteDIDRsp(rsp) => {
+                write!(f, "WriteDIDRsp(")?;
+                self.fmt_did_name(f, rsp.did)?;
+                write!(f, ")")
+            }
+            message => message.fmt(f),
+        }
+    }
+}
+
+impl SymbolicMessage<'_> {
+    fn fmt_did_name(&self, f: &mut Formatter<'_>, did: u16) -> fmt::Result {
+        match self.dids.get(did) {
+            Some(layout) => write!(f, "{}", layout.name),
+            None => write!(f, "0x{did:04x}"),
+        }
+    }
+
+    fn fmt_did_value(&self, f: &mut Formatter<'_>, did: u16, data: &[u8]) -> fmt::Result {
+        let Ok(values) = self.dids.decode(did, data) else {
+            return write!(f, "0x{did:04x}={data:02x?}");
+        };
+        self.fmt_did_name(f, did)?;
+        write!(f, "(")?;
+        for (idx, (name, value)) in values.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        write!(f, ")")
+    }
+
+    fn fmt_dtc(&self, f: &mut Formatter<'_>, dtc: &Dtc) -> fmt::Result {
+        let Some(definition) = self.dtcs.get(dtc) else {
+            return write!(f, "{dtc}");
+        };
+        match &definition.display_code {
+            Some(code) => write!(f, "{code} ({})", definition.name),
+            None => write!(f, "{dtc} ({})", definition.name),
+        }
+    }
+}
+
+fn dtc_list(sub: &DTCRspSubfunction) -> Option<(&'static str, &GotListDtcAndStatusRecord)> {
+    use DTCRspSubfunction::*;
+    match sub {
+        ResponseDTCByStatusMask(r) => Some(("ResponseDTCByStatusMask", r)),
+        ResponseSupportedDTC(r) => Some(("ResponseSupportedDTC", r)),
+        ResponseFirstTestFailedDTC(r) => Some(("ResponseFirstTestFailedDTC", r)),
+        ResponseFirstConfirmedDTC(r) => Some(("ResponseFirstConfirmedDTC", r)),
+        ResponseMostRecentTestFailedDTC(r) => Some(("ResponseMostRecentTestFailedDTC", r)),
+        ResponseMostRecentConfirmedDTC(r) => Some(("ResponseMostRecentConfirmedDTC", r)),
+        ResponseMirrorMemoryDTCByStatusMask(r) => Some(("ResponseMirrorMemoryDTCByStatusMask", r)),
+        ResponseEmissionsOBDDTCByStatusMask(r) => Some(("ResponseEmissionsOBDDTCByStatusMask", r)),
+        ResponseDTCWithPermanentStatus(r) => Some(("ResponseDTCWithPermanentStatus", r)),
+        _ => None,
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{message::TesterPresentReq, uds_write, UdsError, UdsMessage};
+use std::time::{Duration, Instant};
+
+/// Keep-alive of a non-default diagnostic session
+///
+/// A server falls back to the default session if it doesn't receive any
+/// request during S3 (typically 5s). This scheduler emits, through the
+/// provided `send` closure, a `TesterPresent` frame with the suppress positive
+/// response bit set, whenever `interval` elapsed since the last emission.
+///
+/// It doesn't own any thread, and must be polled by the caller, typically
+/// between two [`TransferDataReq`](crate::message::TransferDataReq) of a long
+/// download.
+///
+/// Example:
+/// ```
+/// use std::time::Duration;
+/// use uds_rw::TesterPresentKeepAlive;
+///
+/// let mut keep_alive =
+///     TesterPresentKeepAlive::new(Duration::from_secs(2), |frame: &[u8]| {
+///         assert_eq!(frame, &[0x3e, 0x80]);
+///         Ok(())
+///     });
+/// assert!(keep_alive.poll().unwrap());
+/// assert!(!keep_alive.poll().unwrap());
+/// ```
+pub struct TesterPresentKeepAlive<F> {
+    interval: Duration,
+    last_sent: Option<Instant>,
+    send: F,
+}
+
+impl<F> TesterPresentKeepAlive<F>
+where
+    F: FnMut(&[u8]) -> Result<(), UdsError>,
+{
+    /// Create a keep-alive sending a frame at most every `interval`
+    pub fn new(interval: Duration, send: F) -> Self {
+        Self {
+            interval,
+            last_sent: None,
+            send,
+        }
+    }
+
+    /// Send a `TesterPresent` frame if `interval` elapsed since the last one
+    ///
+    /// Returns `Ok(true)` if a frame was sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the `send` closure.
+    pub fn poll(&mut self) -> Result<bool, UdsError> {
+        self.poll_at(Instant::now())
+    }
+
+    /// Same as [`Self::poll`], with `now` provided by the caller
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the `send` closure.
+    pub fn poll_at(&mut self, now: Instant) -> Result<bool, UdsError> {
+        match self.last_sent {
+            Some(last) if now.saturating_duration_since(last) < self.interval => Ok(false),
+            _ => {
+                self.send_now()?;
+                self.last_sent = Some(now);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Restart the interval, e.g. because another request was just sent and
+    /// already kept the session alive
+    pub fn reset(&mut self) {
+        self.reset_at(Instant::now());
+    }
+
+    /// Same as [`Self::reset`], with `now` provided by the caller
+    pub fn reset_at(&mut self, now: Instant) {
+        self.last_sent = Some(now);
+    }
+
+    fn send_now(&mut self) -> Result<(), UdsError> {
+        let msg = UdsMessage::TesterPresentReq(TesterPresentReq {
+            suppress_positive_response: true,
+        });
+        let mut frame = vec![];
+        uds_write(&mut frame, &msg)?;
+        (self.send)(&frame)
+    }
+}
@@ -17,19 +17,42 @@
 //!
 //! A typical emission sequence using the library would be :
 //! - call [`uds_write()`]
+//!
+//! With the `odx` feature, `OdxDatabase` loads the DID and DTC definitions of
+//! an ODX/PDX diagnostic database, to print messages with symbolic names.
+mod dictionary;
 mod disp;
 mod error;
+mod keepalive;
+#[cfg(feature = "odx")]
+mod odx;
 mod proto;
 mod serde;
 
+pub use dictionary::{
+    DidDictionary, DidField, DidFieldType, DidLayout, DidValue, DtcDefinition, DtcDictionary,
+    SymbolicMessage, DID_ACTIVE_DIAGNOSTIC_SESSION, DID_VIN,
+};
 pub use error::UdsError;
+pub use keepalive::TesterPresentKeepAlive;
+#[cfg(feature = "odx")]
+pub use odx::OdxDatabase;
 
 /// Module containing all the *messages* handled by the API.
 pub mod message {
+    pub use super::proto::communication::*;
     pub use super::proto::did::*;
     pub use super::proto::dtc::*;
+    pub use super::proto::event::*;
+    pub use super::proto::io::*;
+    pub use super::proto::link::*;
+    pub use super::proto::memory::*;
     pub use super::proto::nrc::*;
     pub use super::proto::rawuds::*;
+    pub use super::proto::reset::*;
+    pub use super::proto::routine::*;
+    pub use super::proto::security::*;
+    pub use super::proto::session::*;
     pub use super::proto::transfers::*;
 }
 
@@ -49,6 +72,38 @@ pub enum UdsMessage {
     /// `RawUds` is not an actual message, but a placeholder for the UDS message
     /// missing from [`UdsMessage`], which can be encoded as a raw type array.
     RawUds(message::RawUds),
+    /// Clear DTC request message
+    ClearDTCReq(message::ClearDTCReq),
+    /// Clear DTC response message
+    ClearDTCRsp(message::ClearDTCRsp),
+    /// Communication control request
+    CommunicationControlReq(message::CommunicationControlReq),
+    /// Communication control response
+    CommunicationControlRsp(message::CommunicationControlRsp),
+    /// Control DTC setting request message
+    ControlDTCSettingReq(message::ControlDTCSettingReq),
+    /// Control DTC setting response message
+    ControlDTCSettingRsp(message::ControlDTCSettingRsp),
+    /// Diagnostic session control request
+    DiagnosticSessionControlReq(message::DiagnosticSessionControlReq),
+    /// Diagnostic session control response
+    DiagnosticSessionControlRsp(message::DiagnosticSessionControlRsp),
+    /// Dynamically define DID request
+    DynamicallyDefineDIDReq(message::DynamicallyDefineDIDReq),
+    /// Dynamically define DID response
+    DynamicallyDefineDIDRsp(message::DynamicallyDefineDIDRsp),
+    /// ECU reset request
+    EcuResetReq(message::EcuResetReq),
+    /// ECU reset response
+    EcuResetRsp(message::EcuResetRsp),
+    /// Input Output control request
+    IoControlReq(message::IoControlReq),
+    /// Input Output control response
+    IoControlRsp(message::IoControlRsp),
+    /// Link control request
+    LinkControlReq(message::LinkControlReq),
+    /// Link control response
+    LinkControlRsp(message::LinkControlRsp),
     /// Read DID request message
     ReadDIDReq(message::ReadDIDReq),
     /// Read DID response message
@@ -57,6 +112,14 @@ pub enum UdsMessage {
     ReadDTCReq(message::ReadDTCReq),
     /// Read DTC response message
     ReadDTCRsp(message::ReadDTCRsp),
+    /// Read Memory By Address request
+    ReadMemoryByAddressReq(message::ReadMemoryByAddressReq),
+    /// Read Memory By Address response
+    ReadMemoryByAddressRsp(message::ReadMemoryByAddressRsp),
+    /// Read periodic DID request
+    ReadPeriodicDIDReq(message::ReadPeriodicDIDReq),
+    /// Read periodic DID response
+    ReadPeriodicDIDRsp(message::ReadPeriodicDIDRsp),
     /// Request Download, aka. `TransferStart`
     RequestDownloadReq(message::RequestDownloadReq),
     /// Request Download response
@@ -65,6 +128,26 @@ pub enum UdsMessage {
     RequestFileTransferReq(message::RequestFileTransferReq),
     /// Request File Transfr response
     RequestFileTransferRsp(message::RequestFileTransferRsp),
+    /// Request Upload, to read a memory block from the server
+    RequestUploadReq(message::RequestUploadReq),
+    /// Request Upload response
+    RequestUploadRsp(message::RequestUploadRsp),
+    /// Response on event request
+    ResponseOnEventReq(message::ResponseOnEventReq),
+    /// Response on event response
+    ResponseOnEventRsp(message::ResponseOnEventRsp),
+    /// Routine control request
+    RoutineControlReq(message::RoutineControlReq),
+    /// Routine control response
+    RoutineControlRsp(message::RoutineControlRsp),
+    /// Security access request
+    SecurityAccessReq(message::SecurityAccessReq),
+    /// Security access response
+    SecurityAccessRsp(message::SecurityAccessRsp),
+    /// Tester present request
+    TesterPresentReq(message::TesterPresentReq),
+    /// Tester present response
+    TesterPresentRsp(message::TesterPresentRsp),
     /// Transfer data request
     TransferDataReq(message::TransferDataReq),
     /// Transfer data response
@@ -77,6 +160,10 @@ pub enum UdsMessage {
     WriteDIDReq(message::WriteDIDReq),
     /// Write DID response message
     WriteDIDRsp(message::WriteDIDRsp),
+    /// Write Memory By Address request
+    WriteMemoryByAddressReq(message::WriteMemoryByAddressReq),
+    /// Write Memory By Address response
+    WriteMemoryByAddressRsp(message::WriteMemoryByAddressRsp),
 }
 
 /// Reads a UDS message from a byte stream
@@ -110,6 +197,89 @@ pub fn uds_read<R: Read>(reader: &mut R, payload_length: usize) -> Result<UdsMes
     serde::uds_read(reader, payload_length)
 }
 
+/// Reads a UDS message from a byte stream, with the DID lengths of `lengths`
+///
+/// Behaves as [`uds_read()`], except for the DTC responses holding DID
+/// snapshots (`reportDTCSnapshotRecordByDTCNumber`,
+/// `reportDTCStoredDataByRecordNumber` and
+/// `reportUserDefMemoryDTCSnapshotRecordByDTCNumber`), whose records are split
+/// into their DIDs. If a DID is unknown to `lengths`, the response is decoded
+/// as by [`uds_read()`], with its records left unsplit.
+/// The records of a [`message::ReadDIDRsp`] are split with
+/// [`message::ReadDIDRsp::split_records`], and the control enable mask of an
+/// [`message::IoControlReq`] is split from its control state.
+///
+/// # Errors
+///
+/// Same as [`uds_read()`].
+///
+/// Example:
+/// ```
+/// use std::collections::HashMap;
+/// use uds_rw::{message, uds_read_with_did_lengths, UdsMessage};
+///
+/// let input = vec![
+///     0x59, 0x04, 0x12, 0x34, 0x56, 0x24, 0x01, 0x02, 0xf1, 0x90, 0x01, 0xf1, 0x91, 0x02,
+///     0x03,
+/// ];
+/// let lengths = HashMap::from([(0xf190, 1), (0xf191, 2)]);
+/// let message = uds_read_with_did_lengths(&mut input.as_slice(), input.len(), &lengths);
+/// if let Ok(UdsMessage::ReadDTCRsp(rsp)) = message {
+///     if let message::DTCRspSubfunction::ResponseDTCSnapshotRecordByDTCNumber(r) = rsp.sub {
+///         assert_eq!(r.dtc_snapshot_records[0].dtc_snapshot_record.len(), 2);
+///     }
+/// }
+/// ```
+pub fn uds_read_with_did_lengths<R: Read, S: message::DidLengthSource + ?Sized>(
+    reader: &mut R,
+    payload_length: usize,
+    lengths: &S,
+) -> Result<UdsMessage, UdsError> {
+    serde::uds_read_with_did_lengths(reader, payload_length, lengths)
+}
+
+/// Reads a periodic data message from a byte stream
+///
+/// Periodic data messages are sent by the server after a
+/// [`message::ReadPeriodicDIDReq`], usually on a dedicated identifier of the
+/// transport layer. The messages of type 2 start with the periodic DID instead
+/// of a service identifier, so they cannot be read with [`uds_read()`]; the
+/// messages of type 1 start with the response service identifier and are read
+/// with [`uds_read()`] as a [`message::ReadPeriodicDIDRsp`].
+///
+/// # Errors
+///
+/// If the reader returns an error, the error is returned in [`UdsError::Io`].
+/// If the message is empty, [`UdsError::PayloadLengthTooShort`] is returned.
+///
+/// Example:
+/// ```
+/// use uds_rw::uds_read_periodic;
+///
+/// let input = vec![0x01, 0x0b, 0xb8];
+/// let frame = uds_read_periodic(&mut input.as_slice(), input.len()).unwrap();
+/// assert_eq!(frame.did(), 0xf201);
+/// assert_eq!(frame.data, vec![0x0b, 0xb8]);
+/// ```
+pub fn uds_read_periodic<R: Read>(
+    reader: &mut R,
+    payload_length: usize,
+) -> Result<message::PeriodicDataFrame, UdsError> {
+    serde::uds_read_periodic(reader, payload_length)
+}
+
+/// Writes a periodic data message to a writer
+///
+/// # Errors
+///
+/// If the writer returns an error, the error is returned in [`UdsError::Io`].
+pub fn uds_write_periodic<W: Write>(
+    writer: &mut W,
+    frame: &message::PeriodicDataFrame,
+) -> Result<(), UdsError> {
+    serde::uds_write_periodic(writer, frame)
+}
+
 /// Writes a UDS message to a writer
 ///
 /// This function is normally called to encode the diagnostic payload in an `DoIP`
@@ -128,7 +298,7 @@ pub fn uds_read<R: Read>(reader: &mut R, payload_length: usize) -> Result<UdsMes
 /// ```
 /// use uds_rw::{message, UdsMessage, uds_write};
 ///
-/// let message = UdsMessage::ReadDIDReq(message::ReadDIDReq { did: 0xf190 });
+/// let message = UdsMessage::ReadDIDReq(message::ReadDIDReq { dids: vec![0xf190] });
 /// let mut output : Vec<u8> = vec![];
 /// uds_write(&mut output, &message).unwrap();
 /// assert_eq!(&output, &[0x22, 0xf1, 0x90]);
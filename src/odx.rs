@@ -0,0 +1,321 @@
+use crate::{
+    message::Dtc, DidDictionary, DidField, DidFieldType, DidLayout, DtcDefinition, DtcDictionary,
+    SymbolicMessage, UdsError, UdsMessage,
+};
+use roxmltree::{Document, Node};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, Seek};
+
+const SID_READ_DID: u64 = 0x22;
+
+/// DID and DTC definitions of an ODX (ISO 22901) diagnostic database
+///
+/// DIDs are taken from the diagnostic services reading a data identifier,
+/// with the layout of their positive response, and DTCs from the DTC
+/// definitions of every diagnostic layer. Parameters whose encoding isn't
+/// handled by [`DidFieldType`] (e.g. variable length strings) leave their
+/// DID out of the dictionary.
+///
+/// Example:
+/// ```
+/// use uds_rw::{message, OdxDatabase, UdsMessage};
+///
+/// let odx = r#"<ODX><DIAG-LAYER-CONTAINER><BASE-VARIANTS><BASE-VARIANT>
+///   <DIAG-DATA-DICTIONARY-SPEC><DTC-DOPS><DTC-DOP><DTCS>
+///     <DTC ID="DTC_1"><SHORT-NAME>BatteryVoltageLow</SHORT-NAME>
+///       <TROUBLE-CODE>668443</TROUBLE-CODE>
+///       <DISPLAY-TROUBLE-CODE>P0A1B</DISPLAY-TROUBLE-CODE></DTC>
+///   </DTCS></DTC-DOP></DTC-DOPS></DIAG-DATA-DICTIONARY-SPEC>
+/// </BASE-VARIANT></BASE-VARIANTS></DIAG-LAYER-CONTAINER></ODX>"#;
+/// let database = OdxDatabase::from_odx(odx).unwrap();
+/// let message = UdsMessage::ClearDTCReq(message::ClearDTCReq {
+///     group: message::Dtc::new(0x0a, 0x33, 0x1b),
+///     memory: None,
+/// });
+/// assert_eq!(
+///     database.display(&message).to_string(),
+///     "ClearDTCReq(group=P0A1B (BatteryVoltageLow))"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OdxDatabase {
+    /// Layouts of the DIDs read by the database services
+    pub dids: DidDictionary,
+    /// Definitions of the database DTCs
+    pub dtcs: DtcDictionary,
+}
+
+impl OdxDatabase {
+    /// Load a database from the content of a single ODX file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if `odx` isn't valid XML or
+    /// defines an ID twice.
+    pub fn from_odx(odx: &str) -> Result<Self, UdsError> {
+        Self::from_documents(&[odx])
+    }
+
+    /// Load a database from a PDX archive, i.e. a zip of ODX files
+    ///
+    /// References between the ODX files of the archive are resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if the archive or one of its ODX
+    /// files is invalid, or [`UdsError::Io`] if it cannot be read.
+    pub fn from_pdx<R: Read + Seek>(reader: R) -> Result<Self, UdsError> {
+        let mut archive = zip::ZipArchive::new(reader).map_err(zip_error)?;
+        let mut files = vec![];
+        for idx in 0..archive.len() {
+            let mut file = archive.by_index(idx).map_err(zip_error)?;
+            let is_odx = file
+                .name()
+                .rsplit_once('.')
+                .is_some_and(|(_, ext)| ext.to_ascii_lowercase().starts_with("odx"));
+            if is_odx {
+                let mut odx = String::new();
+                file.read_to_string(&mut odx)?;
+                files.push(odx);
+            }
+        }
+        let files: Vec<&str> = files.iter().map(String::as_str).collect();
+        Self::from_documents(&files)
+    }
+
+    /// Display `message` with the names of the database
+    pub fn display<'a>(&'a self, message: &'a UdsMessage) -> SymbolicMessage<'a> {
+        SymbolicMessage::new(message, &self.dids, &self.dtcs)
+    }
+
+    fn from_documents(files: &[&str]) -> Result<Self, UdsError> {
+        let documents = files
+            .iter()
+            .map(|odx| Document::parse(odx))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| UdsError::EncodingError {
+                msg: format!("Invalid ODX: {e}"),
+            })?;
+        let mut ids = HashMap::new();
+        let mut layers = HashMap::new();
+        for (index, document) in documents.iter().enumerate() {
+            for node in document.descendants() {
+                if let Some(id) = node.attribute("ID") {
+                    if ids.insert((index, id), node).is_some() {
+                        return Err(UdsError::EncodingError {
+                            msg: format!("Invalid ODX: duplicate ID {id}"),
+                        });
+                    }
+                }
+                let is_layer = node.parent() == Some(document.root_element())
+                    || LAYERS.iter().any(|tag| node.has_tag_name(*tag));
+                if let Some(name) = child_text(node, "SHORT-NAME").filter(|_| is_layer) {
+                    if layers
+                        .insert(name, index)
+                        .is_some_and(|other| other != index)
+                    {
+                        return Err(UdsError::EncodingError {
+                            msg: format!("Invalid ODX: {name} is defined in several files"),
+                        });
+                    }
+                }
+            }
+        }
+        let odx = Odx {
+            documents: &documents,
+            ids,
+            layers,
+        };
+        let mut database = Self::default();
+        for node in documents.iter().flat_map(|document| document.descendants()) {
+            if node.has_tag_name("DIAG-SERVICE") {
+                if let Some((did, layout)) = odx.read_did_service(node) {
+                    database.dids.insert(did, layout);
+                }
+            } else if node.has_tag_name("DTC") {
+                if let Some((dtc, definition)) = odx.dtc(node) {
+                    database.dtcs.insert(&dtc, definition);
+                }
+            }
+        }
+        Ok(database)
+    }
+}
+
+fn zip_error(e: zip::result::ZipError) -> UdsError {
+    UdsError::EncodingError {
+        msg: format!("Invalid PDX: {e}"),
+    }
+}
+
+/// Elements whose SHORT-NAME is the target of a `DOCREF`, besides the
+/// top-level elements of an ODX file
+const LAYERS: [&str; 5] = [
+    "PROTOCOL",
+    "FUNCTIONAL-GROUP",
+    "BASE-VARIANT",
+    "ECU-VARIANT",
+    "ECU-SHARED-DATA",
+];
+
+struct Odx<'a, 'input> {
+    documents: &'a [Document<'input>],
+    /// Elements by file index and ID, as IDs are only unique within a file
+    ids: HashMap<(usize, &'a str), Node<'a, 'input>>,
+    /// File index of the layers, by SHORT-NAME
+    layers: HashMap<&'a str, usize>,
+}
+
+impl<'a, 'input> Odx<'a, 'input> {
+    fn read_did_service(&self, service: Node<'a, 'input>) -> Option<(u16, DidLayout)> {
+        let request = self.reference(child(service, "REQUEST-REF")?)?;
+        let mut coded = params(request).filter(|param| xsi_type(*param) == Some("CODED-CONST"));
+        if coded_value(coded.next()?)? != SID_READ_DID {
+            return None;
+        }
+        let did = u16::try_from(coded_value(coded.next()?)?).ok()?;
+
+        let response_ref = child(child(service, "POS-RESPONSE-REFS")?, "POS-RESPONSE-REF")?;
+        let fields = params(self.reference(response_ref)?)
+            .filter(|param| xsi_type(*param) == Some("VALUE"))
+            .map(|param| self.field(child_text(param, "SHORT-NAME")?, param))
+            .collect::<Option<Vec<_>>>()?;
+        if fields.is_empty() {
+            return None;
+        }
+        Some((
+            did,
+            DidLayout::new(child_text(service, "SHORT-NAME")?, fields),
+        ))
+    }
+
+    fn field(&self, name: &str, param: Node<'a, 'input>) -> Option<DidField> {
+        let dop = self.reference(child(param, "DOP-REF")?)?;
+        let coded_type = child(dop, "DIAG-CODED-TYPE")?;
+        let bits: usize = child_text(coded_type, "BIT-LENGTH")?.parse().ok()?;
+        if bits == 0 || !bits.is_multiple_of(8) {
+            return None;
+        }
+        let field_type = match coded_type.attribute("BASE-DATA-TYPE")? {
+            "A_ASCIISTRING" => DidFieldType::Ascii,
+            "A_BYTEFIELD" => DidFieldType::Bytes,
+            base @ ("A_UINT32" | "A_INT32") if bits <= 32 => {
+                self.compu_method(dop, base == "A_INT32")?
+            }
+            _ => return None,
+        };
+        Some(DidField::new(name, bits / 8, field_type))
+    }
+
+    fn compu_method(&self, dop: Node<'a, 'input>, signed: bool) -> Option<DidFieldType> {
+        let compu_method = child(dop, "COMPU-METHOD");
+        let scales = compu_method
+            .and_then(|method| child(method, "COMPU-INTERNAL-TO-PHYS"))
+            .and_then(|phys| child(phys, "COMPU-SCALES"))
+            .into_iter()
+            .flat_map(|scales| scales.children().filter(|n| n.has_tag_name("COMPU-SCALE")));
+        let (scale, offset) = match compu_method.and_then(|method| child_text(method, "CATEGORY")) {
+            None | Some("IDENTICAL") => (1.0, 0.0),
+            Some("LINEAR") => {
+                let coeffs = child(scales.clone().next()?, "COMPU-RATIONAL-COEFFS")?;
+                let numerator = values(child(coeffs, "COMPU-NUMERATOR")?);
+                let denominator = child(coeffs, "COMPU-DENOMINATOR")
+                    .and_then(|denominator| values(denominator).first().copied())
+                    .unwrap_or(1.0);
+                (
+                    numerator.get(1)? / denominator,
+                    numerator.first()? / denominator,
+                )
+            }
+            Some("TEXTTABLE") => {
+                let table: BTreeMap<u64, String> = scales
+                    .filter_map(|scale| {
+                        let raw = parse_number(child_text(scale, "LOWER-LIMIT")?)?;
+                        let label = child_text(child(scale, "COMPU-CONST")?, "VT")?;
+                        Some((raw, label.to_string()))
+                    })
+                    .collect();
+                return Some(DidFieldType::Enum(table));
+            }
+            Some(_) => return None,
+        };
+        let unit = child(dop, "UNIT-REF")
+            .and_then(|unit_ref| self.reference(unit_ref))
+            .and_then(|unit| {
+                child_text(unit, "DISPLAY-NAME").or_else(|| child_text(unit, "SHORT-NAME"))
+            })
+            .map(str::to_string);
+        Some(if signed {
+            DidFieldType::Signed {
+                scale,
+                offset,
+                unit,
+            }
+        } else {
+            DidFieldType::Unsigned {
+                scale,
+                offset,
+                unit,
+            }
+        })
+    }
+
+    fn dtc(&self, dtc: Node<'a, 'input>) -> Option<(Dtc, DtcDefinition)> {
+        let code = u32::try_from(parse_number(child_text(dtc, "TROUBLE-CODE")?)?).ok()?;
+        let definition = DtcDefinition {
+            name: child_text(dtc, "SHORT-NAME")?.to_string(),
+            display_code: child_text(dtc, "DISPLAY-TROUBLE-CODE").map(str::to_string),
+            text: child_text(dtc, "TEXT").map(str::to_string),
+        };
+        Some((Dtc::from(code), definition))
+    }
+
+    fn reference(&self, node: Node<'a, 'input>) -> Option<Node<'a, 'input>> {
+        let index = match node.attribute("DOCREF") {
+            Some(name) => *self.layers.get(name)?,
+            None => self
+                .documents
+                .iter()
+                .position(|document| std::ptr::eq(document, node.document()))?,
+        };
+        self.ids.get(&(index, node.attribute("ID-REF")?)).copied()
+    }
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name(name))
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name)?.text().map(str::trim)
+}
+
+fn params<'a, 'input>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    child(node, "PARAMS")
+        .into_iter()
+        .flat_map(|params| params.children().filter(|n| n.has_tag_name("PARAM")))
+}
+
+fn xsi_type<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.attributes()
+        .find(|attribute| attribute.name() == "type")
+        .map(|attribute| attribute.value())
+}
+
+fn coded_value(param: Node) -> Option<u64> {
+    parse_number(child_text(param, "CODED-VALUE")?)
+}
+
+fn values(node: Node) -> Vec<f64> {
+    node.children()
+        .filter(|n| n.has_tag_name("V"))
+        .filter_map(|v| v.text()?.trim().parse().ok())
+        .collect()
+}
+
+fn parse_number(text: &str) -> Option<u64> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
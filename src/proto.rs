@@ -1,10 +1,19 @@
 use crate::UdsError;
 use std::io::{Read, Write};
 
+pub mod communication;
 pub mod did;
 pub mod dtc;
+pub mod event;
+pub mod io;
+pub mod link;
+pub mod memory;
 pub mod nrc;
 pub mod rawuds;
+pub mod reset;
+pub mod routine;
+pub mod security;
+pub mod session;
 pub mod transfers;
 
 pub trait Payload {
@@ -25,11 +34,27 @@ pub trait Payload {
 pub enum Sid {
     Nrc,
     RawUds(u8),
+    ClearDTC,
+    CommunicationControl,
+    ControlDTCSetting,
+    DiagnosticSessionControl,
+    DynamicallyDefineDID,
+    EcuReset,
+    IoControl,
+    LinkControl,
     ReadDTC,
     ReadDID,
+    ReadMemoryByAddress,
+    ReadPeriodicDID,
     RequestDownload,
     RequestFileTransfer,
+    RequestUpload,
+    ResponseOnEvent,
+    RoutineControl,
+    SecurityAccess,
+    TesterPresent,
     TransferData,
     TransferExit,
     WriteDID,
+    WriteMemoryByAddress,
 }
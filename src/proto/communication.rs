@@ -0,0 +1,71 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Reception/transmission behaviour requested by a [`CommunicationControlReq`]
+pub enum CommunicationControlType {
+    /// Enable reception and transmission
+    #[default]
+    EnableRxAndTx,
+    /// Enable reception, disable transmission
+    EnableRxAndDisableTx,
+    /// Disable reception, enable transmission
+    DisableRxAndEnableTx,
+    /// Disable reception and transmission
+    DisableRxAndTx,
+    /// Enable reception, disable transmission of the node given in
+    /// [`CommunicationControlReq::node_identification_number`]
+    EnableRxAndDisableTxWithEnhancedAddressInformation,
+    /// Enable reception and transmission of the node given in
+    /// [`CommunicationControlReq::node_identification_number`]
+    EnableRxAndTxWithEnhancedAddressInformation,
+    /// Vehicle manufacturer specific control type (0x40..=0x5f)
+    VehicleManufacturerSpecific(u8),
+    /// System supplier specific control type (0x60..=0x7e)
+    SystemSupplierSpecific(u8),
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Network targeted by a [`CommunicationType`]
+pub enum CommunicationSubnet {
+    /// All the networks of the server (subnet number 0x0)
+    #[default]
+    AllNetworks,
+    /// A specific network (subnet number 0x1..=0xe)
+    Network(u8),
+    /// The network on which the request was received (subnet number 0xf)
+    ReceivingNetwork,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Communication type, i.e. which messages on which network are controlled
+pub struct CommunicationType {
+    /// Application messages
+    pub normal_messages: bool,
+    /// Network management messages
+    pub network_management_messages: bool,
+    /// Network on which the control applies
+    pub subnet: CommunicationSubnet,
+    /// Reserved bits 2 and 3, as a value in `0..=3`
+    pub reserved: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Communication control request
+pub struct CommunicationControlReq {
+    /// Requested behaviour
+    pub control_type: CommunicationControlType,
+    /// Controlled messages
+    pub communication_type: CommunicationType,
+    /// Node targeted, only present for the enhanced address information
+    /// control types
+    pub node_identification_number: Option<u16>,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`CommunicationControlReq`]
+pub struct CommunicationControlRsp {
+    /// Applied behaviour
+    pub control_type: CommunicationControlType,
+}
@@ -1,17 +1,28 @@
+use crate::UdsError;
+use std::collections::{BTreeMap, HashMap};
+
 #[derive(Clone, Debug, Default, PartialEq)]
 /// Read DID request
 pub struct ReadDIDReq {
-    /// Diagnostic Identifier
-    pub did: u16,
+    /// Diagnostic Identifiers, read in order by the server
+    pub dids: Vec<u16>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
 /// Read DID response
+///
+/// The DID values carry no length on the wire, so a response to several DIDs
+/// is decoded as the first DID followed by all the remaining bytes. Use
+/// [`ReadDIDRsp::records`] with a [`DidLengthSource`] to split it, or read it
+/// with [`crate::uds_read_with_did_lengths`].
 pub struct ReadDIDRsp {
     /// Diagnostic Identifier
     pub did: u16,
     /// Diagnostic Identifier value
     pub user_data: Vec<u8>,
+    /// (DID, value) records following the first DID, once split by
+    /// [`ReadDIDRsp::split_records`]
+    pub other_records: Vec<(u16, Vec<u8>)>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -29,3 +40,298 @@ pub struct WriteDIDRsp {
     /// Diagnostic Identifier
     pub did: u16,
 }
+
+/// Source of the data length of each DID, usually from the ECU description
+///
+/// Implemented for maps of DID to length and for closures:
+/// ```
+/// use uds_rw::message::{DidLengthSource, ReadDIDRsp};
+///
+/// let rsp = ReadDIDRsp {
+///     did: 0xf190,
+///     user_data: vec![0x30, 0x39, 0xf1, 0x8c, 0x01],
+///     other_records: vec![],
+/// };
+/// let lengths = |did| match did {
+///     0xf190 => Some(2),
+///     0xf18c => Some(1),
+///     _ => None,
+/// };
+/// let records = rsp.records(&lengths).unwrap();
+/// assert_eq!(records, vec![(0xf190, vec![0x30, 0x39]), (0xf18c, vec![0x01])]);
+/// ```
+pub trait DidLengthSource {
+    /// Length in bytes of the `did` value, or `None` if unknown
+    fn did_length(&self, did: u16) -> Option<usize>;
+}
+
+impl<F> DidLengthSource for F
+where
+    F: Fn(u16) -> Option<usize>,
+{
+    fn did_length(&self, did: u16) -> Option<usize> {
+        self(did)
+    }
+}
+
+impl DidLengthSource for HashMap<u16, usize> {
+    fn did_length(&self, did: u16) -> Option<usize> {
+        self.get(&did).copied()
+    }
+}
+
+impl DidLengthSource for BTreeMap<u16, usize> {
+    fn did_length(&self, did: u16) -> Option<usize> {
+        self.get(&did).copied()
+    }
+}
+
+impl ReadDIDRsp {
+    /// Build a response holding several (DID, value) records
+    ///
+    /// Returns the default response if `records` is empty.
+    #[must_use]
+    pub fn from_records(records: &[(u16, Vec<u8>)]) -> Self {
+        let Some(((did, value), others)) = records.split_first() else {
+            return Self::default();
+        };
+        let mut user_data = value.clone();
+        for (did, value) in others {
+            user_data.extend_from_slice(&did.to_be_bytes());
+            user_data.extend_from_slice(value);
+        }
+        Self {
+            did: *did,
+            user_data,
+            other_records: vec![],
+        }
+    }
+
+    /// Move the records following the first DID from `user_data` to
+    /// `other_records`, using the DID lengths of `lengths`
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ReadDIDRsp::records`].
+    pub fn split_records<S: DidLengthSource + ?Sized>(
+        &mut self,
+        lengths: &S,
+    ) -> Result<(), UdsError> {
+        let mut records = self.records(lengths)?.into_iter();
+        if let Some((_, user_data)) = records.next() {
+            self.user_data = user_data;
+            self.other_records = records.collect();
+        }
+        Ok(())
+    }
+
+    /// Split the response into (DID, value) records, followed by
+    /// `other_records`
+    ///
+    /// A DID unknown to `lengths` takes all the remaining bytes of
+    /// `user_data`, so a single DID response is always returned as one record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::PayloadLengthTooShort`] if the response is shorter
+    /// than the lengths given by `lengths`.
+    pub fn records<S: DidLengthSource + ?Sized>(
+        &self,
+        lengths: &S,
+    ) -> Result<Vec<(u16, Vec<u8>)>, UdsError> {
+        let mut records = vec![];
+        let mut did = self.did;
+        let mut data = self.user_data.as_slice();
+        loop {
+            let Some(length) = lengths.did_length(did) else {
+                records.push((did, data.to_vec()));
+                records.extend_from_slice(&self.other_records);
+                return Ok(records);
+            };
+            if data.len() < length {
+                return Err(UdsError::PayloadLengthTooShort {
+                    value: data.len() as u32,
+                    expected: length as u32,
+                });
+            }
+            let (value, rest) = data.split_at(length);
+            records.push((did, value.to_vec()));
+            match rest {
+                [] => {
+                    records.extend_from_slice(&self.other_records);
+                    return Ok(records);
+                }
+                [hi, lo, rest @ ..] => {
+                    did = u16::from_be_bytes([*hi, *lo]);
+                    data = rest;
+                }
+                [_] => {
+                    return Err(UdsError::PayloadLengthTooShort {
+                        value: 1,
+                        expected: 2,
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Part of a source DID value copied into a dynamically defined DID
+pub struct DidSourceElement {
+    /// Source Diagnostic Identifier
+    pub source_did: u16,
+    /// Position of the first copied byte in the source DID value, from 1
+    pub position: u8,
+    /// Number of copied bytes
+    pub memory_size: u8,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Memory block copied into a dynamically defined DID
+pub struct MemorySourceElement {
+    /// Start address of the memory block
+    pub memory_address: usize,
+    /// Number of bytes of the memory block
+    pub memory_size: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Sub-function of a [`DynamicallyDefineDIDReq`] (`definitionType`)
+pub enum DynamicDidDefinitionType {
+    /// Define the DID from parts of other DIDs
+    #[default]
+    DefineByIdentifier,
+    /// Define the DID from memory blocks
+    DefineByMemoryAddress,
+    /// Clear one or all the dynamically defined DIDs
+    ClearDynamicallyDefinedDataIdentifier,
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Definition of a dynamically defined DID
+pub enum DynamicDidDefinition {
+    /// Define `did` from parts of other DIDs, in order
+    DefineByIdentifier {
+        /// Dynamically defined Diagnostic Identifier
+        did: u16,
+        /// Parts of the source DIDs
+        sources: Vec<DidSourceElement>,
+    },
+    /// Define `did` from memory blocks, in order
+    DefineByMemoryAddress {
+        /// Dynamically defined Diagnostic Identifier
+        did: u16,
+        /// Size in byte of the `memory_size` fields (1 to 5 bytes)
+        memory_size_bytes: u8,
+        /// Size in byte of the `memory_address` fields (1 to 5 bytes)
+        memory_address_bytes: u8,
+        /// Memory blocks
+        sources: Vec<MemorySourceElement>,
+    },
+    /// Clear `did`, or all the dynamically defined DIDs if `None`
+    ClearDynamicallyDefinedDataIdentifier {
+        /// Dynamically defined Diagnostic Identifier
+        did: Option<u16>,
+    },
+}
+
+impl Default for DynamicDidDefinition {
+    fn default() -> Self {
+        Self::ClearDynamicallyDefinedDataIdentifier { did: None }
+    }
+}
+
+impl DynamicDidDefinition {
+    /// Sub-function of the definition
+    #[must_use]
+    pub fn definition_type(&self) -> DynamicDidDefinitionType {
+        match self {
+            Self::DefineByIdentifier { .. } => DynamicDidDefinitionType::DefineByIdentifier,
+            Self::DefineByMemoryAddress { .. } => DynamicDidDefinitionType::DefineByMemoryAddress,
+            Self::ClearDynamicallyDefinedDataIdentifier { .. } => {
+                DynamicDidDefinitionType::ClearDynamicallyDefinedDataIdentifier
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Dynamically define DID request
+pub struct DynamicallyDefineDIDReq {
+    /// Definition of the DID
+    pub definition: DynamicDidDefinition,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`DynamicallyDefineDIDReq`]
+pub struct DynamicallyDefineDIDRsp {
+    /// Sub-function of the request
+    pub definition_type: DynamicDidDefinitionType,
+    /// Dynamically defined Diagnostic Identifier, absent when all the
+    /// dynamically defined DIDs are cleared
+    pub did: Option<u16>,
+}
+
+/// High byte of the periodic DIDs, read by a [`ReadPeriodicDIDReq`]
+pub const PERIODIC_DID_HIGH_BYTE: u8 = 0xf2;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Rate of the periodic data messages of a [`ReadPeriodicDIDReq`]
+pub enum TransmissionMode {
+    /// Send at the slow rate of the server
+    #[default]
+    SendAtSlowRate,
+    /// Send at the medium rate of the server
+    SendAtMediumRate,
+    /// Send at the fast rate of the server
+    SendAtFastRate,
+    /// Stop sending the periodic DIDs
+    StopSending,
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Read periodic DID request
+pub struct ReadPeriodicDIDReq {
+    /// Rate of the periodic data messages
+    pub transmission_mode: TransmissionMode,
+    /// Low bytes of the periodic DIDs (`0xf2xx`), may be empty to stop
+    /// sending all of them
+    pub periodic_ids: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Read periodic DID response, the values being sent afterwards in
+/// [`PeriodicDataFrame`]s
+pub struct ReadPeriodicDIDRsp {
+    /// Periodic data message of type 1, i.e. sent after the response
+    /// service identifier, absent for the response to the request itself
+    pub frame: Option<PeriodicDataFrame>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Periodic data message, sent by the server after a [`ReadPeriodicDIDReq`]
+///
+/// The messages of type 2 carry no service identifier, so they are read with
+/// [`crate::uds_read_periodic`] rather than [`crate::uds_read`]. The messages
+/// of type 1 are read by [`crate::uds_read`] as a [`ReadPeriodicDIDRsp`].
+pub struct PeriodicDataFrame {
+    /// Low byte of the periodic DID
+    pub periodic_id: u8,
+    /// Periodic DID value
+    pub data: Vec<u8>,
+}
+
+impl PeriodicDataFrame {
+    /// Periodic DID (`0xf2xx`) of the message
+    #[must_use]
+    pub fn did(&self) -> u16 {
+        u16::from_be_bytes([PERIODIC_DID_HIGH_BYTE, self.periodic_id])
+    }
+}
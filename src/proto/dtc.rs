@@ -1,5 +1,8 @@
+use crate::UdsError;
 use serde::{Deserialize, Serialize};
 use serde_dis::{DeserializeWithDiscriminant, SerializeWithDiscriminant};
+use std::ops::{BitAnd, BitOr, BitOrAssign, Not};
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 /// Diagnostic troubleshooting code response
@@ -15,6 +18,99 @@ pub struct ReadDTCReq {
     pub sub: DTCReqSubfunction,
 }
 
+#[derive(Clone, PartialEq, Debug, Default)]
+/// Clear diagnostic information request
+pub struct ClearDTCReq {
+    /// Group of DTC to clear, either a single DTC, a functional group or
+    /// [`Dtc::ALL_GROUPS`]
+    pub group: Dtc,
+    /// Memory to clear (ISO 14229-1:2020), or the server default memory if
+    /// `None`
+    pub memory: Option<MemorySelection>,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+/// Clear diagnostic information response
+pub struct ClearDTCRsp {}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+/// Setting requested by a [`ControlDTCSettingReq`]
+pub enum DTCSettingType {
+    /// Resume the update of DTC status bits
+    #[default]
+    On,
+    /// Stop the update of DTC status bits
+    Off,
+    /// Vehicle manufacturer specific setting (0x40..=0x5f)
+    VehicleManufacturerSpecific(u8),
+    /// System supplier specific setting (0x60..=0x7e)
+    SystemSupplierSpecific(u8),
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+/// `DTCSettingControlOptionRecord` of a [`ControlDTCSettingReq`]
+pub enum DtcSettingOption {
+    /// No option record, the setting applies to all DTCs
+    #[default]
+    None,
+    /// DTCs or groups of DTCs the setting applies to
+    Dtcs(Vec<Dtc>),
+    /// Option record left undecoded, as its content is manufacturer specific
+    Raw(Vec<u8>),
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+/// Control DTC setting request
+///
+/// The option record is decoded as [`DtcSettingOption::Raw`]; use
+/// [`ControlDTCSettingReq::split_dtcs`] if it is known to be a list of DTCs.
+pub struct ControlDTCSettingReq {
+    /// On or off
+    pub setting_type: DTCSettingType,
+    /// `DTCSettingControlOptionRecord`
+    pub option: DtcSettingOption,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+impl ControlDTCSettingReq {
+    /// Decode a [`DtcSettingOption::Raw`] option record as a list of DTCs
+    ///
+    /// Does nothing for the other option records.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if the option record isn't a
+    /// multiple of 3 bytes.
+    pub fn split_dtcs(&mut self) -> Result<(), UdsError> {
+        let DtcSettingOption::Raw(record) = &self.option else {
+            return Ok(());
+        };
+        if !record.len().is_multiple_of(3) {
+            return Err(UdsError::EncodingError {
+                msg: "DTCSettingControlOptionRecord is not a list of DTCs".to_string(),
+            });
+        }
+        let dtcs = record
+            .chunks_exact(3)
+            .map(|dtc| Dtc {
+                dtc: [dtc[0], dtc[1], dtc[2]],
+            })
+            .collect();
+        self.option = DtcSettingOption::Dtcs(dtcs);
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+/// Control DTC setting response
+pub struct ControlDTCSettingRsp {
+    /// Applied setting
+    pub setting_type: DTCSettingType,
+}
+
 #[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
 /// DTC code
 pub struct Dtc {
@@ -22,8 +118,175 @@ pub struct Dtc {
     pub dtc: [u8; 3],
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+/// Format of the DTCs reported by a server (`DTCFormatIdentifier`)
+pub enum DtcFormat {
+    /// SAE J2012-DA DTC format 00, as defined in ISO 15031-6
+    Iso15031,
+    /// ISO 14229-1 DTC format
+    #[default]
+    Iso14229,
+    /// SAE J1939-73 DTC format
+    SaeJ1939,
+    /// ISO 11992-4 DTC format
+    Iso11992,
+    /// SAE J2012-DA DTC format 04, as used by WWH-OBD
+    SaeJ2012WwhObd,
+    /// Reserved for future definition
+    Reserved(u8),
+}
+
+/// Display of a [`Dtc`] in a given [`DtcFormat`]
+pub struct DtcDisplay<'a> {
+    pub(crate) dtc: &'a Dtc,
+    pub(crate) format: DtcFormat,
+}
+
+/// Byte of bit flags, with a constant and a display name per bit
+macro_rules! flags_byte {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$flag_meta:meta])* $flag:ident = $bit:literal => $label:literal,)*
+        }
+    ) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+        $(#[$meta])*
+        pub struct $name(u8);
+
+        impl $name {
+            $($(#[$flag_meta])* pub const $flag: $name = $name($bit);)*
+
+            /// Flags and their display names, in bit order
+            pub(crate) const FLAGS: &'static [($name, &'static str)] = &[$(($name::$flag, $label)),*];
+
+            /// Flags with the raw `bits`
+            #[must_use]
+            pub const fn from_bits(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            /// Raw bits of the flags
+            #[must_use]
+            pub const fn bits(self) -> u8 {
+                self.0
+            }
+
+            /// Whether no bit is set
+            #[must_use]
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Whether all the bits of `other` are set
+            #[must_use]
+            pub const fn contains(self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Set the bits of `other`
+            pub fn insert(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+
+            /// Clear the bits of `other`
+            pub fn remove(&mut self, other: $name) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl From<u8> for $name {
+            fn from(value: u8) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl Not for $name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+    };
+}
+
+flags_byte! {
+    /// DTC status byte, one bit per test result of the DTC
+    pub struct DtcStatus {
+        /// Result of the most recent test is failed
+        TEST_FAILED = 0x01 => "testFailed",
+        /// Test failed during the current operation cycle
+        TEST_FAILED_THIS_OPERATION_CYCLE = 0x02 => "testFailedThisOperationCycle",
+        /// Test failed during the current or last completed operation cycle
+        PENDING_DTC = 0x04 => "pendingDTC",
+        /// Failure confirmed and stored in long term memory
+        CONFIRMED_DTC = 0x08 => "confirmedDTC",
+        /// Test not completed since the last clear of the DTCs
+        TEST_NOT_COMPLETED_SINCE_LAST_CLEAR = 0x10 => "testNotCompletedSinceLastClear",
+        /// Test failed at least once since the last clear of the DTCs
+        TEST_FAILED_SINCE_LAST_CLEAR = 0x20 => "testFailedSinceLastClear",
+        /// Test not completed during the current operation cycle
+        TEST_NOT_COMPLETED_THIS_OPERATION_CYCLE = 0x40 => "testNotCompletedThisOperationCycle",
+        /// Warning indicator requested by the server
+        WARNING_INDICATOR_REQUESTED = 0x80 => "warningIndicatorRequested",
+    }
+}
+
+flags_byte! {
+    /// DTC severity byte, made of the severity bits and of the DTC class bits of
+    /// GTR-compliant WWH-OBD systems
+    pub struct DtcSeverity {
+        /// DTC class 0, unclassified
+        DTC_CLASS_0 = 0x01 => "DTCClass_0",
+        /// DTC class 1, malfunction without impact on emissions or OBD
+        DTC_CLASS_1 = 0x02 => "DTCClass_1",
+        /// DTC class 2, malfunction with moderate impact on emissions or OBD
+        DTC_CLASS_2 = 0x04 => "DTCClass_2",
+        /// DTC class 3, malfunction with severe impact on emissions or OBD
+        DTC_CLASS_3 = 0x08 => "DTCClass_3",
+        /// DTC class 4, malfunction with severe impact requiring immediate action
+        DTC_CLASS_4 = 0x10 => "DTCClass_4",
+        /// Failure only requires maintenance
+        MAINTENANCE_ONLY = 0x20 => "maintenanceOnly",
+        /// Failure must be checked at the next halt
+        CHECK_AT_NEXT_HALT = 0x40 => "checkAtNextHalt",
+        /// Failure must be checked immediately
+        CHECK_IMMEDIATELY = 0x80 => "checkImmediately",
+    }
+}
+
 /// DTC status mask
-pub type DTCStatusMask = u8;
+pub type DTCStatusMask = DtcStatus;
 /// DTC mask
 pub type DTCMaskRecord = Dtc;
 /// Snapshot record number
@@ -32,18 +295,36 @@ pub type DTCSnapshotRecordNumber = u8;
 pub type DTCStoredDataRecordNumber = u8;
 /// Ext data record number
 pub type DTCExtDataRecordNumber = u8;
-/// Severity mask
-pub type DTCSeverityMaskRecord = u16;
 /// Memory selection
 pub type MemorySelection = u8;
 /// Functional group identifier
 pub type FunctionalGroupIdentifier = u8;
+/// DTC readiness group identifier
+pub type DTCReadinessGroupIdentifier = u8;
 /// DTC severity
-pub type DTCSeverity = u8;
+pub type DTCSeverity = DtcSeverity;
+/// DTC severity mask
+pub type DTCSeverityMask = DtcSeverity;
 /// Function unit
 pub type DTCFunctionalUnit = u8;
 
 impl Dtc {
+    /// Group of all DTCs
+    pub const ALL_GROUPS: Dtc = Dtc {
+        dtc: [0xff, 0xff, 0xff],
+    };
+    /// Group of emissions-related DTCs
+    pub const EMISSIONS_SYSTEM_GROUP: Dtc = Dtc {
+        dtc: [0xff, 0xff, 0x33],
+    };
+    /// Group of safety-related DTCs
+    pub const SAFETY_SYSTEM_GROUP: Dtc = Dtc {
+        dtc: [0xff, 0xff, 0xd0],
+    };
+
+    const J1939_SPN_MAX: u32 = 0x7_ffff;
+    const J1939_FMI_MAX: u8 = 0x1f;
+
     /// Create a DTC code
     #[must_use]
     pub fn new(dtc_high_byte: u8, dtc_middle_byte: u8, dtc_low_byte: u8) -> Dtc {
@@ -51,6 +332,103 @@ impl Dtc {
             dtc: [dtc_high_byte, dtc_middle_byte, dtc_low_byte],
         }
     }
+
+    /// Parse a SAE J2012 code, e.g. `P0A1B-1C`, the failure type being
+    /// optional
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if `code` isn't a SAE J2012 code.
+    pub fn from_j2012(code: &str) -> Result<Dtc, UdsError> {
+        let invalid = || UdsError::EncodingError {
+            msg: format!("{code} is not a SAE J2012 DTC"),
+        };
+        let (code, failure_type) = code.split_once('-').unwrap_or((code, "00"));
+        let mut chars = code.chars();
+        let system: u8 = match chars.next().map(|c| c.to_ascii_uppercase()) {
+            Some('P') => 0,
+            Some('C') => 1,
+            Some('B') => 2,
+            Some('U') => 3,
+            _ => return Err(invalid()),
+        };
+        let digits = chars.as_str();
+        let is_hex = |s: &str, len| s.len() == len && s.chars().all(|c| c.is_ascii_hexdigit());
+        if !is_hex(digits, 4) || !is_hex(failure_type, 2) {
+            return Err(invalid());
+        }
+        let digits = u16::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        if digits >> 14 != 0 {
+            return Err(invalid());
+        }
+        let failure_type = u8::from_str_radix(failure_type, 16).map_err(|_| invalid())?;
+        let [high, middle] = digits.to_be_bytes();
+        Ok(Dtc::new((system << 6) | high, middle, failure_type))
+    }
+
+    /// SAE J2012 code of the DTC, e.g. `P0A1B-1C`
+    #[must_use]
+    pub fn to_j2012(&self) -> String {
+        let system = ['P', 'C', 'B', 'U'][usize::from(self.dtc[0] >> 6)];
+        format!(
+            "{system}{:02X}{:02X}-{:02X}",
+            self.dtc[0] & 0x3f,
+            self.dtc[1],
+            self.dtc[2]
+        )
+    }
+
+    /// Create a SAE J1939-73 DTC from its suspect parameter number (19 bits)
+    /// and failure mode identifier (5 bits)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if `spn` or `fmi` is out of range.
+    pub fn from_j1939(spn: u32, fmi: u8) -> Result<Dtc, UdsError> {
+        if spn > Self::J1939_SPN_MAX || fmi > Self::J1939_FMI_MAX {
+            return Err(UdsError::EncodingError {
+                msg: format!("SPN {spn} FMI {fmi} is not a SAE J1939 DTC"),
+            });
+        }
+        let [spn_low, spn_middle, spn_high, _] = spn.to_le_bytes();
+        Ok(Dtc::new(spn_low, spn_middle, (spn_high << 5) | fmi))
+    }
+
+    /// Suspect parameter number of a SAE J1939-73 DTC
+    #[must_use]
+    pub fn spn(&self) -> u32 {
+        u32::from_le_bytes([self.dtc[0], self.dtc[1], self.dtc[2] >> 5, 0])
+    }
+
+    /// Failure mode identifier of a SAE J1939-73 DTC
+    #[must_use]
+    pub fn fmi(&self) -> u8 {
+        self.dtc[2] & Self::J1939_FMI_MAX
+    }
+
+    /// Display the DTC in the `format` reported by the server
+    #[must_use]
+    pub fn display(&self, format: DtcFormat) -> DtcDisplay<'_> {
+        DtcDisplay { dtc: self, format }
+    }
+}
+
+impl FromStr for Dtc {
+    type Err = UdsError;
+
+    /// Parse a SAE J2012 code (`P0A1B-1C`) or an hexadecimal code (`0x0a1b1c`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("0x") {
+            Some(hex) if hex.len() == 6 => {
+                u32::from_str_radix(hex, 16)
+                    .map(Dtc::from)
+                    .map_err(|_| UdsError::EncodingError {
+                        msg: format!("{s} is not an hexadecimal DTC"),
+                    })
+            }
+            _ => Dtc::from_j2012(s),
+        }
+    }
 }
 
 #[derive(
@@ -90,11 +468,15 @@ pub enum DTCReqSubfunction {
     ) = 0x18,
     ReportUserDefMemoryDTCExtDataRecordByDTCNumber(ReportUserDefMemoryDTCExtDataRecordByDTCNumber) =
         0x19,
-    Reserved2 = 0x1a, // 0x1a..0x41
+    ReportSupportedDTCExtDataRecord(ReportSupportedDTCExtDataRecord) = 0x1a,
+    Reserved2 = 0x1b, // 0x1b..0x41
     ReportWWHOBDDTCByMaskRecord(ReportWWHOBDDTCByMaskRecord) = 0x42,
     Reserved3 = 0x43, // 0x43..0x54,
     ReportWWHOBDDTCWithPermanentStatus(ReportWWHOBDDTCWithPermanentStatus) = 0x55,
-    Reserved4 = 0x56, // 0x56..0x7f
+    ReportDTCInformationByDTCReadinessGroupIdentifier(
+        ReportDTCInformationByDTCReadinessGroupIdentifier,
+    ) = 0x56,
+    Reserved4 = 0x57, // 0x57..0x7f
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -123,8 +505,10 @@ pub struct ByDTCMaskRecordRecordNumber {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 /// Filter by DTC severity record
 pub struct ByDTCSeverityMaskRecord {
-    /// Mask of the severity
-    pub mask: DTCSeverityMaskRecord,
+    /// Mask of the DTC severity
+    pub severity_mask: DTCSeverityMask,
+    /// Mask of the DTC status
+    pub status_mask: DTCStatusMask,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -201,10 +585,30 @@ pub struct ReportUserDefMemoryDTCExtDataRecordByDTCNumber {
     pub memory: MemorySelection,
 }
 
-#[allow(missing_docs)]
-pub type ReportWWHOBDDTCByMaskRecord = ByFunctionalGroupIdentifier;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Filter of WWH-OBD DTCs by functional group, status and severity
+pub struct ReportWWHOBDDTCByMaskRecord {
+    /// Functional group identifier
+    pub group: FunctionalGroupIdentifier,
+    /// Mask of the DTC status
+    pub status_mask: DTCStatusMask,
+    /// Mask of the DTC severity
+    pub severity_mask: DTCSeverityMask,
+}
+
 #[allow(missing_docs)]
 pub type ReportWWHOBDDTCWithPermanentStatus = ByFunctionalGroupIdentifier;
+#[allow(missing_docs)]
+pub type ReportSupportedDTCExtDataRecord = ReportDTCExtDataRecordByRecordNumber;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Filter by functional group and DTC readiness group
+pub struct ReportDTCInformationByDTCReadinessGroupIdentifier {
+    /// Functional group identifier
+    pub group: FunctionalGroupIdentifier,
+    /// DTC readiness group identifier
+    pub readiness_group: DTCReadinessGroupIdentifier,
+}
 
 /*********** Responses **********/
 #[derive(
@@ -245,11 +649,15 @@ pub enum DTCRspSubfunction {
     ResponseUserDefMemoryDTCExtDataRecordByDTCNumber(
         ResponseUserDefMemoryDTCExtDataRecordByDTCNumber,
     ) = 0x19,
-    Reserved2 = 0x1a, // 0x1a..0x41,
-    ResponseWWHOBDDTCByMaskRecord(Vec<u8>) = 0x42,
+    ResponseSupportedDTCExtDataRecord(ResponseSupportedDTCExtDataRecord) = 0x1a,
+    Reserved2 = 0x1b, // 0x1b..0x41,
+    ResponseWWHOBDDTCByMaskRecord(ResponseWWHOBDDTCByMaskRecord) = 0x42,
     Reserved3 = 0x43, // 0x43..-0x54,
-    ResponseWWHOBDDTCWithPermanentStatus(Vec<u8>) = 0x55,
-    Reserved4 = 0x56, // 0x56..0x7f
+    ResponseWWHOBDDTCWithPermanentStatus(ResponseWWHOBDDTCWithPermanentStatus) = 0x55,
+    ResponseDTCInformationByDTCReadinessGroupIdentifier(
+        ResponseDTCInformationByDTCReadinessGroupIdentifier,
+    ) = 0x56,
+    Reserved4 = 0x57, // 0x57..0x7f
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -258,10 +666,11 @@ pub struct GotDTCCount {
     /// DTC mask
     pub mask: DTCStatusMask,
     /// DTC format
-    pub format: u8,
+    pub format: DtcFormat,
     ///  Number of DTC found
     pub count: u16,
 }
+
 #[allow(missing_docs)]
 pub type ResponseNumberOfDTCByStatusMask = GotDTCCount;
 #[allow(missing_docs)]
@@ -323,7 +732,7 @@ pub struct ResponseDTCSnapshotRecordByDTCNumber {
     /// DTC and its status
     pub dtc_status: DtcAndStatusRecord,
     /// Snapshot records
-    pub dtc_snapshot_records: DtcSnapshotRecords,
+    pub dtc_snapshot_records: Vec<DtcSnapshotRecords>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -407,6 +816,69 @@ pub struct DTCAndSeverityRecord {
     pub dtc_status: DtcAndStatusRecord,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Response of the DTCs supporting an `ExtData` record
+pub struct ResponseSupportedDTCExtDataRecord {
+    /// Availability mask
+    pub availability_mask: DTCStatusMask,
+    /// `ExtData` record number
+    pub record: DTCExtDataRecordNumber,
+    /// DTC and status records
+    pub dtcs: Vec<DtcAndStatusRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Response of the DTCs of a functional group and DTC readiness group
+pub struct ResponseDTCInformationByDTCReadinessGroupIdentifier {
+    /// Functional group identifier
+    pub group: FunctionalGroupIdentifier,
+    /// Availability mask
+    pub availability_mask: DTCStatusMask,
+    /// DTC format
+    pub format: DtcFormat,
+    /// DTC readiness group identifier
+    pub readiness_group: DTCReadinessGroupIdentifier,
+    /// DTC and status records
+    pub dtcs: Vec<DtcAndStatusRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Response of WWH-OBD DTCs matching a functional group, status and severity
+pub struct ResponseWWHOBDDTCByMaskRecord {
+    /// Functional group identifier
+    pub group: FunctionalGroupIdentifier,
+    /// Status availability mask
+    pub status_availability_mask: DTCStatusMask,
+    /// Severity availability mask
+    pub severity_availability_mask: DTCSeverityMask,
+    /// DTC format
+    pub format: DtcFormat,
+    /// DTC, severity and status records
+    pub records: Vec<WWHOBDDTCAndSeverityRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// WWH-OBD DTC and severity record
+pub struct WWHOBDDTCAndSeverityRecord {
+    /// DTC severity
+    pub severity: DTCSeverity,
+    /// DTC and its status
+    pub dtc_status: DtcAndStatusRecord,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Response of WWH-OBD DTCs with permanent status of a functional group
+pub struct ResponseWWHOBDDTCWithPermanentStatus {
+    /// Functional group identifier
+    pub group: FunctionalGroupIdentifier,
+    /// Status availability mask
+    pub availability_mask: DTCStatusMask,
+    /// DTC format
+    pub format: DtcFormat,
+    /// DTC and status records
+    pub records: Vec<DtcAndStatusRecord>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 /// Response of DTC and fault counter
 pub struct ResponseDTCFaultDetectionCounter {
@@ -470,8 +942,8 @@ pub struct RecordNumberAndIdentifierAndSnapshot {
     pub record_number: DTCStoredDataRecordNumber,
     /// Number of identifiers
     pub number_identifiers: u8,
-    /// DID with its value
-    pub record: DidSnapshot,
+    /// DIDs and their values
+    pub records: DTCSnapshotRecord,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -500,6 +972,12 @@ impl From<u32> for Dtc {
     }
 }
 
+impl From<&Dtc> for u32 {
+    fn from(value: &Dtc) -> Self {
+        u32::from_be_bytes([0, value.dtc[0], value.dtc[1], value.dtc[2]])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Dtc;
@@ -507,7 +985,7 @@ mod tests {
 
     #[test]
     fn test_bincode_ser() {
-        let rn = ReportNumberOfDTCByStatusMask { mask: 0x27 };
+        let rn = ReportNumberOfDTCByStatusMask { mask: 0x27.into() };
         let serialized = bincode::serialize(&rn).unwrap();
         assert_eq!(&serialized, &[0x27]);
     }
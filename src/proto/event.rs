@@ -0,0 +1,130 @@
+use crate::proto::dtc::DTCStatusMask;
+use crate::UdsMessage;
+
+/// Event window time without end, until the events are stopped or cleared
+pub const EVENT_WINDOW_INFINITE: u8 = 0x02;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Operator of an [`RoeEvent::OnComparisonOfValues`] event
+pub enum ComparisonLogic {
+    /// The DID value is smaller than the reference
+    #[default]
+    LessThan,
+    /// The DID value is larger than the reference
+    LargerThan,
+    /// The DID value is equal to the reference
+    Equal,
+    /// The DID value is not equal to the reference
+    NotEqual,
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Comparison of a DID value triggering an [`RoeEvent::OnComparisonOfValues`]
+pub struct ValueComparison {
+    /// Diagnostic Identifier holding the compared value
+    pub did: u16,
+    /// Comparison operator
+    pub logic: ComparisonLogic,
+    /// Value compared to the DID value
+    pub reference: u32,
+    /// Hysteresis of the comparison, in percent
+    pub hysteresis: u8,
+    /// Sign, bit length and bit offset of the compared value in the DID value
+    /// (`localization`)
+    pub localization: u16,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Event type of a [`ResponseOnEventReq`], with its `eventTypeRecord`
+pub enum RoeEvent {
+    /// Stop sending responses on events
+    #[default]
+    StopResponseOnEvent,
+    /// Respond when the status of a DTC changes
+    OnDTCStatusChange {
+        /// Status bits whose change triggers the event
+        mask: DTCStatusMask,
+    },
+    /// Respond periodically
+    OnTimerInterrupt {
+        /// Rate of the timer, server specific
+        timer_rate: u8,
+    },
+    /// Respond when the value of a DID changes
+    OnChangeOfDataIdentifier {
+        /// Diagnostic Identifier watched for changes
+        did: u16,
+    },
+    /// Report the events set up in the server
+    ReportActivatedEvents,
+    /// Start sending responses on the events set up
+    StartResponseOnEvent,
+    /// Clear the events set up
+    ClearResponseOnEvent,
+    /// Respond when a DID value fulfills a comparison
+    OnComparisonOfValues(ValueComparison),
+    /// Reserved event type, with all the bytes following the event window time
+    Reserved {
+        /// Event type
+        event_type: u8,
+        /// Event type record and service to respond to
+        record: Vec<u8>,
+    },
+}
+
+impl RoeEvent {
+    /// Whether the event is set up with a service to respond to, i.e. it isn't
+    /// a start, stop, clear or report of the events
+    #[must_use]
+    pub fn is_setup(&self) -> bool {
+        matches!(
+            self,
+            Self::OnDTCStatusChange { .. }
+                | Self::OnTimerInterrupt { .. }
+                | Self::OnChangeOfDataIdentifier { .. }
+                | Self::OnComparisonOfValues(_)
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response on event request
+pub struct ResponseOnEventReq {
+    /// Event type and record
+    pub event: RoeEvent,
+    /// If set, the event set up is kept across power cycles (`storageState`)
+    pub store_event: bool,
+    /// Duration of the events, e.g. [`EVENT_WINDOW_INFINITE`], absent for
+    /// [`RoeEvent::ReportActivatedEvents`] and optional for the start, stop
+    /// and clear of the events
+    pub event_window_time: Option<u8>,
+    /// Request run by the server when the event occurs, present for the events
+    /// set up
+    pub service_to_respond_to: Option<Box<UdsMessage>>,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`ResponseOnEventReq`]
+pub struct ResponseOnEventRsp {
+    /// Event type and record of the request
+    pub event: RoeEvent,
+    /// Whether the event set up is kept across power cycles
+    pub store_event: bool,
+    /// Number of identified events, or of activated events for the start,
+    /// stop, clear and report of the events
+    pub number_of_events: u8,
+    /// Duration of the events, absent for
+    /// [`RoeEvent::ReportActivatedEvents`]
+    pub event_window_time: Option<u8>,
+    /// Request run by the server when the event occurs, present for the events
+    /// set up
+    pub service_to_respond_to: Option<Box<UdsMessage>>,
+    /// Records of the activated events for
+    /// [`RoeEvent::ReportActivatedEvents`], left undecoded since their
+    /// service to respond to carries no length
+    pub activated_events: Vec<u8>,
+}
@@ -0,0 +1,79 @@
+use crate::proto::did::DidLengthSource;
+use crate::UdsError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// How the server controls an input or output (`inputOutputControlParameter`)
+pub enum IoControlParameter {
+    /// Give the control back to the ECU
+    #[default]
+    ReturnControlToEcu,
+    /// Reset the input or output to its default value
+    ResetToDefault,
+    /// Freeze the input or output at its current value
+    FreezeCurrentState,
+    /// Set the input or output to the given control state
+    ShortTermAdjustment,
+    /// Reserved value
+    Reserved(u8),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Input Output control request
+///
+/// The control state and the control enable mask carry no length on the
+/// wire. Only [`IoControlParameter::ShortTermAdjustment`] has a control state,
+/// so for that parameter all the bytes after it are decoded as control state;
+/// use [`IoControlReq::split_control_enable_mask`] with a [`DidLengthSource`]
+/// to split them.
+pub struct IoControlReq {
+    /// Diagnostic Identifier of the controlled input or output
+    pub did: u16,
+    /// How the input or output is controlled
+    pub parameter: IoControlParameter,
+    /// Control state, in the format of the DID value
+    pub control_state: Vec<u8>,
+    /// Mask of the DID value parts to control, if the DID has several ones
+    pub control_enable_mask: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Input Output control response
+pub struct IoControlRsp {
+    /// Diagnostic Identifier of the controlled input or output
+    pub did: u16,
+    /// How the input or output is controlled
+    pub parameter: IoControlParameter,
+    /// Control state, in the format of the DID value
+    pub control_state: Vec<u8>,
+}
+
+impl IoControlReq {
+    /// Move the bytes after the DID value of the control state to the control
+    /// enable mask, using the DID length of `lengths`
+    ///
+    /// Does nothing if the DID length is unknown or if the control state is
+    /// not longer than it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if the request already has a
+    /// control enable mask.
+    pub fn split_control_enable_mask<S: DidLengthSource + ?Sized>(
+        &mut self,
+        lengths: &S,
+    ) -> Result<(), UdsError> {
+        let Some(length) = lengths.did_length(self.did) else {
+            return Ok(());
+        };
+        if self.control_state.len() <= length {
+            return Ok(());
+        }
+        if self.control_enable_mask.is_some() {
+            return Err(UdsError::EncodingError {
+                msg: format!("IoControlReq of DID 0x{:04x} already has a mask", self.did),
+            });
+        }
+        self.control_enable_mask = Some(self.control_state.split_off(length));
+        Ok(())
+    }
+}
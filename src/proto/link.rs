@@ -0,0 +1,91 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Baudrate transition requested by a [`LinkControlReq`]
+pub enum LinkControlType {
+    /// Verify that the transition to a fixed baudrate is possible
+    #[default]
+    VerifyModeTransitionWithFixedParameter,
+    /// Verify that the transition to a specific baudrate is possible
+    VerifyModeTransitionWithSpecificParameter,
+    /// Transition to the verified baudrate
+    TransitionMode,
+    /// Vehicle manufacturer specific control type (0x40..=0x5f)
+    VehicleManufacturerSpecific(u8),
+    /// System supplier specific control type (0x60..=0x7e)
+    SystemSupplierSpecific(u8),
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Baudrate of a [`LinkControlReq`]
+pub enum LinkBaudrate {
+    /// Standard PC baudrate of 9.6 kBaud
+    Pc9600,
+    /// Standard PC baudrate of 19.2 kBaud
+    Pc19200,
+    /// Standard PC baudrate of 38.4 kBaud
+    Pc38400,
+    /// Standard PC baudrate of 57.6 kBaud
+    Pc57600,
+    /// Standard PC baudrate of 115.2 kBaud
+    Pc115200,
+    /// CAN baudrate of 125 kBaud
+    Can125000,
+    /// CAN baudrate of 250 kBaud
+    Can250000,
+    /// CAN baudrate of 500 kBaud
+    Can500000,
+    /// CAN baudrate of 1 MBaud
+    Can1000000,
+    /// Baudrate of the programming setup
+    ProgrammingSetup,
+    /// Reserved fixed baudrate identifier
+    Reserved(u8),
+    /// Specific baudrate in Baud, encoded on 3 bytes
+    Specific(u32),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Link control request
+pub struct LinkControlReq {
+    /// Requested transition
+    pub control_type: LinkControlType,
+    /// Baudrate to verify, only present for the verify mode transition
+    /// control types
+    pub baudrate: Option<LinkBaudrate>,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`LinkControlReq`]
+pub struct LinkControlRsp {
+    /// Requested transition
+    pub control_type: LinkControlType,
+}
+
+impl LinkControlReq {
+    /// Request verifying that the transition to `baudrate` is possible
+    #[must_use]
+    pub fn verify(baudrate: LinkBaudrate) -> Self {
+        let control_type = match baudrate {
+            LinkBaudrate::Specific(_) => LinkControlType::VerifyModeTransitionWithSpecificParameter,
+            _ => LinkControlType::VerifyModeTransitionWithFixedParameter,
+        };
+        Self {
+            control_type,
+            baudrate: Some(baudrate),
+            suppress_positive_response: false,
+        }
+    }
+
+    /// Request the transition to the verified baudrate
+    #[must_use]
+    pub fn transition() -> Self {
+        Self {
+            control_type: LinkControlType::TransitionMode,
+            baudrate: None,
+            suppress_positive_response: false,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Request to read a memory block from the server
+pub struct ReadMemoryByAddressReq {
+    /// Size in byte of the `memory_size` field (1 to 5 bytes)
+    pub memory_size_bytes: u8,
+    /// Size in byte of the `memory_address` field (1 to 5 bytes)
+    pub memory_address_bytes: u8,
+    /// Start address of the memory block to read
+    pub memory_address: usize,
+    /// Number of bytes to read
+    pub memory_size: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`ReadMemoryByAddressReq`]
+pub struct ReadMemoryByAddressRsp {
+    /// Content of the memory block
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Request to write a memory block in the server
+pub struct WriteMemoryByAddressReq {
+    /// Size in byte of the `memory_size` field (1 to 5 bytes)
+    pub memory_size_bytes: u8,
+    /// Size in byte of the `memory_address` field (1 to 5 bytes)
+    pub memory_address_bytes: u8,
+    /// Start address of the memory block to write
+    pub memory_address: usize,
+    /// Number of bytes to write
+    pub memory_size: usize,
+    /// Data to write at `memory_address`
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`WriteMemoryByAddressReq`], echoing the written block
+pub struct WriteMemoryByAddressRsp {
+    /// Size in byte of the `memory_size` field (1 to 5 bytes)
+    pub memory_size_bytes: u8,
+    /// Size in byte of the `memory_address` field (1 to 5 bytes)
+    pub memory_address_bytes: u8,
+    /// Start address of the written memory block
+    pub memory_address: usize,
+    /// Number of bytes written
+    pub memory_size: usize,
+}
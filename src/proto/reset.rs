@@ -0,0 +1,41 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Kind of reset requested by an [`EcuResetReq`]
+pub enum ResetType {
+    /// Reset simulating a power-on/start-up sequence
+    #[default]
+    HardReset,
+    /// Reset simulating an ignition key off/on sequence
+    KeyOffOnReset,
+    /// Restart of the application program
+    SoftReset,
+    /// Power down as soon as the ignition key is off
+    EnableRapidPowerShutDown,
+    /// Disable a previously enabled rapid power shut down
+    DisableRapidPowerShutDown,
+    /// Vehicle manufacturer specific reset (0x40..=0x5f)
+    VehicleManufacturerSpecific(u8),
+    /// System supplier specific reset (0x60..=0x7e)
+    SystemSupplierSpecific(u8),
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// ECU reset request
+pub struct EcuResetReq {
+    /// Kind of reset to perform
+    pub reset_type: ResetType,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to an [`EcuResetReq`]
+pub struct EcuResetRsp {
+    /// Kind of reset performed
+    pub reset_type: ResetType,
+    /// Minimum time in seconds the server stays in power down, only present
+    /// for [`ResetType::EnableRapidPowerShutDown`] (0xff: failure or time not
+    /// available)
+    pub power_down_time: Option<u8>,
+}
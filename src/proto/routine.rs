@@ -0,0 +1,47 @@
+/// ISO 14229 routine erasing a memory area before download
+pub const ROUTINE_ERASE_MEMORY: u16 = 0xff00;
+/// ISO 14229 routine checking the consistency of the downloaded software
+pub const ROUTINE_CHECK_PROGRAMMING_DEPENDENCIES: u16 = 0xff01;
+/// Routine checking a downloaded memory block (checksum, signature, ...)
+pub const ROUTINE_CHECK_MEMORY: u16 = 0x0202;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Action requested by a [`RoutineControlReq`]
+pub enum RoutineControlType {
+    /// Start the routine
+    #[default]
+    StartRoutine,
+    /// Stop a running routine
+    StopRoutine,
+    /// Get the results of a routine
+    RequestRoutineResults,
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Routine control request
+pub struct RoutineControlReq {
+    /// Action on the routine
+    pub control_type: RoutineControlType,
+    /// Routine identifier, as [`ROUTINE_ERASE_MEMORY`] or OEM specific
+    pub routine_identifier: u16,
+    /// Routine parameters (`routineControlOptionRecord`), routine specific
+    pub option_record: Vec<u8>,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`RoutineControlReq`]
+pub struct RoutineControlRsp {
+    /// Action on the routine
+    pub control_type: RoutineControlType,
+    /// Routine identifier
+    pub routine_identifier: u16,
+    /// Routine information (`routineInfo`), present if any byte follows the
+    /// routine identifier
+    pub routine_info: Option<u8>,
+    /// Routine results (`routineStatusRecord`), routine specific
+    pub status_record: Vec<u8>,
+}
@@ -0,0 +1,109 @@
+use crate::UdsError;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Step of the seed/key exchange requested by a [`SecurityAccessReq`]
+pub enum SecurityAccessRequest {
+    /// requestSeed (odd sub-function), with the optional
+    /// securityAccessDataRecord (OEM specific)
+    RequestSeed(Vec<u8>),
+    /// sendKey (even sub-function), with the key computed from the seed
+    SendKey(Vec<u8>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Security access request
+pub struct SecurityAccessReq {
+    /// Security level, 1 for sub-functions 0x01/0x02, 2 for 0x03/0x04, ...
+    pub level: u8,
+    /// Either a seed request or a key
+    pub request: SecurityAccessRequest,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Answer of the server to a [`SecurityAccessRequest`]
+pub enum SecurityAccessResponse {
+    /// Seed to compute the key from (all zeroes if the level is already
+    /// unlocked)
+    Seed(Vec<u8>),
+    /// The key was accepted, the level is unlocked
+    #[default]
+    KeyAccepted,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`SecurityAccessReq`]
+pub struct SecurityAccessRsp {
+    /// Security level, 1 for sub-functions 0x01/0x02, 2 for 0x03/0x04, ...
+    pub level: u8,
+    /// Either the seed or the key acknowledge
+    pub response: SecurityAccessResponse,
+}
+
+/// Algorithm computing a security access key from a seed
+///
+/// The algorithms are OEM specific, and this trait is the extension point to
+/// plug them into [`SecurityAccessRsp::key_request`]. Any closure
+/// `Fn(u8, &[u8]) -> Result<Vec<u8>, UdsError>` implements it.
+///
+/// Example:
+/// ```
+/// use uds_rw::message::{SecurityAccessRsp, SecurityAccessResponse, SecurityAccessRequest};
+///
+/// let xor = |_level: u8, seed: &[u8]| Ok(seed.iter().map(|b| b ^ 0x5a).collect());
+/// let rsp = SecurityAccessRsp {
+///     level: 1,
+///     response: SecurityAccessResponse::Seed(vec![0x12, 0x34]),
+/// };
+/// let req = rsp.key_request(&xor).unwrap();
+/// assert_eq!(req.request, SecurityAccessRequest::SendKey(vec![0x48, 0x6e]));
+/// ```
+pub trait SecurityKeyAlgorithm {
+    /// Compute the key for the security `level` from the server `seed`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key cannot be computed, e.g. for an unsupported
+    /// level or seed length.
+    fn compute_key(&self, level: u8, seed: &[u8]) -> Result<Vec<u8>, UdsError>;
+}
+
+impl<F> SecurityKeyAlgorithm for F
+where
+    F: Fn(u8, &[u8]) -> Result<Vec<u8>, UdsError>,
+{
+    fn compute_key(&self, level: u8, seed: &[u8]) -> Result<Vec<u8>, UdsError> {
+        self(level, seed)
+    }
+}
+
+impl Default for SecurityAccessRequest {
+    fn default() -> Self {
+        Self::RequestSeed(vec![])
+    }
+}
+
+impl SecurityAccessRsp {
+    /// Build the sendKey request answering this seed response
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UdsError::EncodingError`] if this response doesn't carry a
+    /// seed, or the error of the `algorithm`.
+    pub fn key_request<A: SecurityKeyAlgorithm + ?Sized>(
+        &self,
+        algorithm: &A,
+    ) -> Result<SecurityAccessReq, UdsError> {
+        let SecurityAccessResponse::Seed(seed) = &self.response else {
+            return Err(UdsError::EncodingError {
+                msg: "SecurityAccessRsp doesn't hold a seed".to_string(),
+            });
+        };
+        Ok(SecurityAccessReq {
+            level: self.level,
+            request: SecurityAccessRequest::SendKey(algorithm.compute_key(self.level, seed)?),
+            suppress_positive_response: false,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Diagnostic session requested by a [`DiagnosticSessionControlReq`]
+pub enum DiagnosticSessionType {
+    /// Default session, active at ECU start-up
+    #[default]
+    DefaultSession,
+    /// Session used to reprogram the server
+    ProgrammingSession,
+    /// Session unlocking adjustment, actuator control ... services
+    ExtendedDiagnosticSession,
+    /// Session dedicated to safety systems (airbags, ...)
+    SafetySystemDiagnosticSession,
+    /// Vehicle manufacturer specific session (0x40..=0x5f)
+    VehicleManufacturerSpecific(u8),
+    /// System supplier specific session (0x60..=0x7e)
+    SystemSupplierSpecific(u8),
+    /// Reserved for future definition by ISO 14229
+    Reserved(u8),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Diagnostic session control request
+pub struct DiagnosticSessionControlReq {
+    /// Session to switch to
+    pub session_type: DiagnosticSessionType,
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`DiagnosticSessionControlReq`]
+pub struct DiagnosticSessionControlRsp {
+    /// Session the server switched to
+    pub session_type: DiagnosticSessionType,
+    /// Maximum time for the server to start its response (1ms resolution)
+    pub p2_server_max: Duration,
+    /// Maximum time for the server to start its response after a
+    /// `RequestCorrectlyReceivedResponsePending` (10ms resolution)
+    pub p2_star_server_max: Duration,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Tester present request, keeping a non-default session alive
+pub struct TesterPresentReq {
+    /// If set, the server shall not send a positive response
+    pub suppress_positive_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response to a [`TesterPresentReq`]
+pub struct TesterPresentRsp {}
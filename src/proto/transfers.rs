@@ -1,6 +1,7 @@
 #[derive(Clone, Debug, Default, PartialEq)]
-/// Request to download a file
-pub struct RequestDownloadReq {
+/// Request to transfer a memory block, shared by [`RequestDownloadReq`] and
+/// [`RequestUploadReq`]
+pub struct TransferRequest {
     /// Compression method (OEM specific)
     pub compression_method: u8,
     /// Encryption method (OEM specific)
@@ -9,21 +10,33 @@ pub struct RequestDownloadReq {
     pub memory_size_bytes: u8,
     /// Size in byte of the `memory_address` field (i.e. u8, u16, u32 ...)
     pub memory_address_bytes: u8,
-    /// Memory address to store the file (OEM specific)
+    /// Memory address of the block to store or to upload (OEM specific)
     pub memory_address: usize,
-    /// Memory size of the file to store
+    /// Memory size of the block to store or to upload
     pub memory_size: usize,
 }
 
+/// Request to download a file
+pub type RequestDownloadReq = TransferRequest;
+/// Request to upload a memory block from the server
+pub type RequestUploadReq = TransferRequest;
+
 #[derive(Clone, Debug, Default, PartialEq)]
-/// Response to a [`RequestDownloadReq`]
-pub struct RequestDownloadRsp {
+/// Response to a [`TransferRequest`], shared by [`RequestDownloadRsp`] and
+/// [`RequestUploadRsp`]
+pub struct TransferResponse {
     /// Size in byte of the `max_block_size` field (ie. u8, u16, u32 ...)
     pub max_block_size_bytes: u8,
-    /// Maximum size of data in [`TransferDataReq::data`]
+    /// Maximum size of data in [`TransferDataReq::data`] for a download, or in
+    /// [`TransferDataRsp::data`] for an upload
     pub max_block_size: usize,
 }
 
+/// Response to a [`RequestDownloadReq`]
+pub type RequestDownloadRsp = TransferResponse;
+/// Response to a [`RequestUploadReq`]
+pub type RequestUploadRsp = TransferResponse;
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 /// Defines the behaviour of `RequestFileTransferReq` operation
@@ -99,6 +112,8 @@ pub struct TransferDataReq {
 pub struct TransferDataRsp {
     /// The sequence block number acknowledge
     pub block_sequence_counter: u8,
+    /// The block content for an upload, empty for a download
+    pub data: Vec<u8>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -1,26 +1,54 @@
 use super::proto::Sid;
 use crate::{
     proto::{
-        did::{ReadDIDReq, ReadDIDRsp, WriteDIDReq, WriteDIDRsp},
-        dtc::{ReadDTCReq, ReadDTCRsp},
+        communication::*,
+        did::{
+            DidLengthSource, DynamicallyDefineDIDReq, DynamicallyDefineDIDRsp, PeriodicDataFrame,
+            ReadDIDReq, ReadDIDRsp, ReadPeriodicDIDReq, ReadPeriodicDIDRsp, WriteDIDReq,
+            WriteDIDRsp,
+        },
+        dtc::{
+            ClearDTCReq, ClearDTCRsp, ControlDTCSettingReq, ControlDTCSettingRsp, ReadDTCReq,
+            ReadDTCRsp,
+        },
+        event::{ResponseOnEventReq, ResponseOnEventRsp},
+        io::{IoControlReq, IoControlRsp},
+        link::{LinkControlReq, LinkControlRsp},
+        memory::*,
         nrc::Nrc,
         rawuds::RawUds,
+        reset::*,
+        routine::*,
+        security::*,
+        session::*,
         transfers::*,
     },
     UdsError, UdsMessage,
 };
 use byteorder::{ReadBytesExt, WriteBytesExt};
 use std::io::{Read, Write};
+mod communication;
 mod deserializer;
 mod did;
 mod dtc;
+mod event;
+mod io;
+mod link;
+mod memory;
 mod nrc;
 mod rawuds;
+mod reset;
+mod routine;
+mod security;
 mod serializer;
+mod session;
 mod transfers;
 
 use super::proto::Payload;
 
+/// Sub-function bit asking the server not to send a positive response
+const SUPPRESS_POSITIVE_RESPONSE: u8 = 0x80;
+
 pub fn uds_write<W: Write>(writer: &mut W, msg: &UdsMessage) -> Result<(), UdsError> {
     if let UdsMessage::RawUds(_) = msg {
     } else {
@@ -40,6 +68,41 @@ pub fn uds_read<R: Read>(reader: &mut R, payload_length: usize) -> Result<UdsMes
     }
 }
 
+pub fn uds_read_periodic<R: Read>(
+    reader: &mut R,
+    payload_length: usize,
+) -> Result<PeriodicDataFrame, UdsError> {
+    PeriodicDataFrame::read(reader, payload_length)
+}
+
+pub fn uds_write_periodic<W: Write>(
+    writer: &mut W,
+    frame: &PeriodicDataFrame,
+) -> Result<(), UdsError> {
+    frame.write(writer)
+}
+
+pub fn uds_read_with_did_lengths<R: Read, S: DidLengthSource + ?Sized>(
+    reader: &mut R,
+    payload_length: usize,
+    lengths: &S,
+) -> Result<UdsMessage, UdsError> {
+    let mut data = vec![0u8; payload_length];
+    reader.read_exact(&mut data)?;
+    if let Some((0x59, payload)) = data.split_first() {
+        if let Some(rsp) = ReadDTCRsp::read_did_snapshots(payload, lengths) {
+            return Ok(UdsMessage::ReadDTCRsp(rsp?));
+        }
+    }
+    let mut message = uds_read(&mut data.as_slice(), payload_length)?;
+    match &mut message {
+        UdsMessage::IoControlReq(req) => req.split_control_enable_mask(lengths)?,
+        UdsMessage::ReadDIDRsp(rsp) => rsp.split_records(lengths)?,
+        _ => {}
+    }
+    Ok(message)
+}
+
 fn uds_read_rsp<R: Read>(
     reader: &mut R,
     sid: Sid,
@@ -48,15 +111,41 @@ fn uds_read_rsp<R: Read>(
     let mut uds: UdsMessage = match sid {
         Sid::Nrc => UdsMessage::Nrc(Nrc::default()),
         Sid::RawUds(sid) => UdsMessage::RawUds(RawUds { data: vec![sid] }),
+        Sid::ClearDTC => UdsMessage::ClearDTCRsp(ClearDTCRsp::default()),
+        Sid::CommunicationControl => {
+            UdsMessage::CommunicationControlRsp(CommunicationControlRsp::default())
+        }
+        Sid::ControlDTCSetting => UdsMessage::ControlDTCSettingRsp(ControlDTCSettingRsp::default()),
+        Sid::DiagnosticSessionControl => {
+            UdsMessage::DiagnosticSessionControlRsp(DiagnosticSessionControlRsp::default())
+        }
+        Sid::DynamicallyDefineDID => {
+            UdsMessage::DynamicallyDefineDIDRsp(DynamicallyDefineDIDRsp::default())
+        }
+        Sid::EcuReset => UdsMessage::EcuResetRsp(EcuResetRsp::default()),
+        Sid::IoControl => UdsMessage::IoControlRsp(IoControlRsp::default()),
+        Sid::LinkControl => UdsMessage::LinkControlRsp(LinkControlRsp::default()),
         Sid::ReadDID => UdsMessage::ReadDIDRsp(ReadDIDRsp::default()),
         Sid::ReadDTC => UdsMessage::ReadDTCRsp(ReadDTCRsp::default()),
+        Sid::ReadMemoryByAddress => {
+            UdsMessage::ReadMemoryByAddressRsp(ReadMemoryByAddressRsp::default())
+        }
+        Sid::ReadPeriodicDID => UdsMessage::ReadPeriodicDIDRsp(ReadPeriodicDIDRsp::default()),
         Sid::RequestDownload => UdsMessage::RequestDownloadRsp(RequestDownloadRsp::default()),
         Sid::RequestFileTransfer => {
             UdsMessage::RequestFileTransferRsp(RequestFileTransferRsp::default())
         }
+        Sid::RequestUpload => UdsMessage::RequestUploadRsp(RequestUploadRsp::default()),
+        Sid::ResponseOnEvent => UdsMessage::ResponseOnEventRsp(ResponseOnEventRsp::default()),
+        Sid::RoutineControl => UdsMessage::RoutineControlRsp(RoutineControlRsp::default()),
+        Sid::SecurityAccess => UdsMessage::SecurityAccessRsp(SecurityAccessRsp::default()),
+        Sid::TesterPresent => UdsMessage::TesterPresentRsp(TesterPresentRsp::default()),
         Sid::TransferData => UdsMessage::TransferDataRsp(TransferDataRsp::default()),
         Sid::TransferExit => UdsMessage::TransferExitRsp(TransferExitRsp::default()),
         Sid::WriteDID => UdsMessage::WriteDIDRsp(WriteDIDRsp::default()),
+        Sid::WriteMemoryByAddress => {
+            UdsMessage::WriteMemoryByAddressRsp(WriteMemoryByAddressRsp::default())
+        }
     };
     uds.read_replace(reader, payload_length)?;
     Ok(uds)
@@ -70,15 +159,41 @@ fn uds_read_req<R: Read>(
     let mut uds: UdsMessage = match sid {
         Sid::Nrc => UdsMessage::RawUds(RawUds::default()),
         Sid::RawUds(sid) => UdsMessage::RawUds(RawUds { data: vec![sid] }),
+        Sid::ClearDTC => UdsMessage::ClearDTCReq(ClearDTCReq::default()),
+        Sid::CommunicationControl => {
+            UdsMessage::CommunicationControlReq(CommunicationControlReq::default())
+        }
+        Sid::ControlDTCSetting => UdsMessage::ControlDTCSettingReq(ControlDTCSettingReq::default()),
+        Sid::DiagnosticSessionControl => {
+            UdsMessage::DiagnosticSessionControlReq(DiagnosticSessionControlReq::default())
+        }
+        Sid::DynamicallyDefineDID => {
+            UdsMessage::DynamicallyDefineDIDReq(DynamicallyDefineDIDReq::default())
+        }
+        Sid::EcuReset => UdsMessage::EcuResetReq(EcuResetReq::default()),
+        Sid::IoControl => UdsMessage::IoControlReq(IoControlReq::default()),
+        Sid::LinkControl => UdsMessage::LinkControlReq(LinkControlReq::default()),
         Sid::ReadDID => UdsMessage::ReadDIDReq(ReadDIDReq::default()),
         Sid::ReadDTC => UdsMessage::ReadDTCReq(ReadDTCReq::default()),
+        Sid::ReadMemoryByAddress => {
+            UdsMessage::ReadMemoryByAddressReq(ReadMemoryByAddressReq::default())
+        }
+        Sid::ReadPeriodicDID => UdsMessage::ReadPeriodicDIDReq(ReadPeriodicDIDReq::default()),
         Sid::RequestDownload => UdsMessage::RequestDownloadReq(RequestDownloadReq::default()),
         Sid::RequestFileTransfer => {
             UdsMessage::RequestFileTransferReq(RequestFileTransferReq::default())
         }
+        Sid::RequestUpload => UdsMessage::RequestUploadReq(RequestUploadReq::default()),
+        Sid::ResponseOnEvent => UdsMessage::ResponseOnEventReq(ResponseOnEventReq::default()),
+        Sid::RoutineControl => UdsMessage::RoutineControlReq(RoutineControlReq::default()),
+        Sid::SecurityAccess => UdsMessage::SecurityAccessReq(SecurityAccessReq::default()),
+        Sid::TesterPresent => UdsMessage::TesterPresentReq(TesterPresentReq::default()),
         Sid::TransferData => UdsMessage::TransferDataReq(TransferDataReq::default()),
         Sid::TransferExit => UdsMessage::TransferExitReq(TransferExitReq::default()),
         Sid::WriteDID => UdsMessage::WriteDIDReq(WriteDIDReq::default()),
+        Sid::WriteMemoryByAddress => {
+            UdsMessage::WriteMemoryByAddressReq(WriteMemoryByAddressReq::default())
+        }
     };
     uds.read_replace(reader, payload_length)?;
     Ok(uds)
@@ -97,14 +212,30 @@ impl From<u8> for Sid {
     fn from(value: u8) -> Self {
         use Sid::*;
         match value {
+            0x10 => DiagnosticSessionControl,
+            0x11 => EcuReset,
+            0x14 => ClearDTC,
             0x19 => ReadDTC,
             0x22 => ReadDID,
+            0x23 => ReadMemoryByAddress,
+            0x27 => SecurityAccess,
+            0x28 => CommunicationControl,
+            0x2a => ReadPeriodicDID,
+            0x2c => DynamicallyDefineDID,
             0x2e => WriteDID,
+            0x2f => IoControl,
+            0x31 => RoutineControl,
             0x34 => RequestDownload,
+            0x35 => RequestUpload,
             0x36 => TransferData,
             0x37 => TransferExit,
             0x38 => RequestFileTransfer,
+            0x3d => WriteMemoryByAddress,
+            0x3e => TesterPresent,
             0x3f => Nrc,
+            0x85 => ControlDTCSetting,
+            0x86 => ResponseOnEvent,
+            0x87 => LinkControl,
             _ => RawUds(value),
         }
     }
@@ -115,46 +246,110 @@ impl From<&UdsMessage> for u8 {
         use UdsMessage::*;
         match value {
             Nrc(_) => 0x7f,
+            ClearDTCReq(_) => 0x14,
+            ClearDTCRsp(_) => 0x54,
+            CommunicationControlReq(_) => 0x28,
+            CommunicationControlRsp(_) => 0x68,
+            ControlDTCSettingReq(_) => 0x85,
+            ControlDTCSettingRsp(_) => 0xc5,
+            DiagnosticSessionControlReq(_) => 0x10,
+            DiagnosticSessionControlRsp(_) => 0x50,
+            DynamicallyDefineDIDReq(_) => 0x2c,
+            DynamicallyDefineDIDRsp(_) => 0x6c,
+            EcuResetReq(_) => 0x11,
+            EcuResetRsp(_) => 0x51,
+            IoControlReq(_) => 0x2f,
+            IoControlRsp(_) => 0x6f,
+            LinkControlReq(_) => 0x87,
+            LinkControlRsp(_) => 0xc7,
             ReadDIDReq(_) => 0x22,
             ReadDIDRsp(_) => 0x62,
             ReadDTCReq(_) => 0x19,
             ReadDTCRsp(_) => 0x59,
+            ReadMemoryByAddressReq(_) => 0x23,
+            ReadMemoryByAddressRsp(_) => 0x63,
+            ReadPeriodicDIDReq(_) => 0x2a,
+            ReadPeriodicDIDRsp(_) => 0x6a,
             RequestDownloadReq(_) => 0x34,
             RequestDownloadRsp(_) => 0x74,
             RequestFileTransferReq(_) => 0x38,
             RequestFileTransferRsp(_) => 0x78,
+            RequestUploadReq(_) => 0x35,
+            RequestUploadRsp(_) => 0x75,
+            ResponseOnEventReq(_) => 0x86,
+            ResponseOnEventRsp(_) => 0xc6,
+            RoutineControlReq(_) => 0x31,
+            RoutineControlRsp(_) => 0x71,
+            SecurityAccessReq(_) => 0x27,
+            SecurityAccessRsp(_) => 0x67,
+            TesterPresentReq(_) => 0x3e,
+            TesterPresentRsp(_) => 0x7e,
             TransferDataReq(_) => 0x36,
             TransferDataRsp(_) => 0x76,
             TransferExitReq(_) => 0x37,
             TransferExitRsp(_) => 0x77,
             WriteDIDReq(_) => 0x2e,
             WriteDIDRsp(_) => 0x6e,
+            WriteMemoryByAddressReq(_) => 0x3d,
+            WriteMemoryByAddressRsp(_) => 0x7d,
             RawUds(u) => u.data[0],
         }
     }
 }
 
 impl UdsMessage {
-    #[allow(dead_code)]
     fn length(&self) -> usize {
         use UdsMessage::*;
         1 + match self {
             Nrc(p) => p.length(),
-            RawUds(p) => p.length(),
+            // The SID is the first byte of the raw data
+            RawUds(p) => p.length().saturating_sub(1),
+            ClearDTCReq(p) => p.length(),
+            ClearDTCRsp(p) => p.length(),
+            CommunicationControlReq(p) => p.length(),
+            CommunicationControlRsp(p) => p.length(),
+            ControlDTCSettingReq(p) => p.length(),
+            ControlDTCSettingRsp(p) => p.length(),
+            DiagnosticSessionControlReq(p) => p.length(),
+            DiagnosticSessionControlRsp(p) => p.length(),
+            DynamicallyDefineDIDReq(p) => p.length(),
+            DynamicallyDefineDIDRsp(p) => p.length(),
+            EcuResetReq(p) => p.length(),
+            EcuResetRsp(p) => p.length(),
+            IoControlReq(p) => p.length(),
+            IoControlRsp(p) => p.length(),
+            LinkControlReq(p) => p.length(),
+            LinkControlRsp(p) => p.length(),
             ReadDIDReq(p) => p.length(),
             ReadDIDRsp(p) => p.length(),
             ReadDTCReq(p) => p.length(),
             ReadDTCRsp(p) => p.length(),
+            ReadMemoryByAddressReq(p) => p.length(),
+            ReadMemoryByAddressRsp(p) => p.length(),
+            ReadPeriodicDIDReq(p) => p.length(),
+            ReadPeriodicDIDRsp(p) => p.length(),
             RequestDownloadReq(p) => p.length(),
             RequestDownloadRsp(p) => p.length(),
             RequestFileTransferReq(p) => p.length(),
             RequestFileTransferRsp(p) => p.length(),
+            RequestUploadReq(p) => p.length(),
+            RequestUploadRsp(p) => p.length(),
+            ResponseOnEventReq(p) => p.length(),
+            ResponseOnEventRsp(p) => p.length(),
+            RoutineControlReq(p) => p.length(),
+            RoutineControlRsp(p) => p.length(),
+            SecurityAccessReq(p) => p.length(),
+            SecurityAccessRsp(p) => p.length(),
+            TesterPresentReq(p) => p.length(),
+            TesterPresentRsp(p) => p.length(),
             TransferDataReq(p) => p.length(),
             TransferDataRsp(p) => p.length(),
             TransferExitReq(p) => p.length(),
             TransferExitRsp(p) => p.length(),
             WriteDIDReq(p) => p.length(),
             WriteDIDRsp(p) => p.length(),
+            WriteMemoryByAddressReq(p) => p.length(),
+            WriteMemoryByAddressRsp(p) => p.length(),
         }
     }
 
@@ -167,20 +362,52 @@ impl UdsMessage {
         match self {
             Nrc(p) => p.read_replace(reader, payload_length),
             RawUds(p) => p.read_replace(reader, payload_length),
+            ClearDTCReq(p) => p.read_replace(reader, payload_length),
+            ClearDTCRsp(p) => p.read_replace(reader, payload_length),
+            CommunicationControlReq(p) => p.read_replace(reader, payload_length),
+            CommunicationControlRsp(p) => p.read_replace(reader, payload_length),
+            ControlDTCSettingReq(p) => p.read_replace(reader, payload_length),
+            ControlDTCSettingRsp(p) => p.read_replace(reader, payload_length),
+            DiagnosticSessionControlReq(p) => p.read_replace(reader, payload_length),
+            DiagnosticSessionControlRsp(p) => p.read_replace(reader, payload_length),
+            DynamicallyDefineDIDReq(p) => p.read_replace(reader, payload_length),
+            DynamicallyDefineDIDRsp(p) => p.read_replace(reader, payload_length),
+            EcuResetReq(p) => p.read_replace(reader, payload_length),
+            EcuResetRsp(p) => p.read_replace(reader, payload_length),
+            IoControlReq(p) => p.read_replace(reader, payload_length),
+            IoControlRsp(p) => p.read_replace(reader, payload_length),
+            LinkControlReq(p) => p.read_replace(reader, payload_length),
+            LinkControlRsp(p) => p.read_replace(reader, payload_length),
             ReadDIDReq(p) => p.read_replace(reader, payload_length),
             ReadDIDRsp(p) => p.read_replace(reader, payload_length),
             ReadDTCReq(p) => p.read_replace(reader, payload_length),
             ReadDTCRsp(p) => p.read_replace(reader, payload_length),
+            ReadMemoryByAddressReq(p) => p.read_replace(reader, payload_length),
+            ReadMemoryByAddressRsp(p) => p.read_replace(reader, payload_length),
+            ReadPeriodicDIDReq(p) => p.read_replace(reader, payload_length),
+            ReadPeriodicDIDRsp(p) => p.read_replace(reader, payload_length),
             RequestDownloadReq(p) => p.read_replace(reader, payload_length),
             RequestDownloadRsp(p) => p.read_replace(reader, payload_length),
             RequestFileTransferReq(p) => p.read_replace(reader, payload_length),
             RequestFileTransferRsp(p) => p.read_replace(reader, payload_length),
+            RequestUploadReq(p) => p.read_replace(reader, payload_length),
+            RequestUploadRsp(p) => p.read_replace(reader, payload_length),
+            ResponseOnEventReq(p) => p.read_replace(reader, payload_length),
+            ResponseOnEventRsp(p) => p.read_replace(reader, payload_length),
+            RoutineControlReq(p) => p.read_replace(reader, payload_length),
+            RoutineControlRsp(p) => p.read_replace(reader, payload_length),
+            SecurityAccessReq(p) => p.read_replace(reader, payload_length),
+            SecurityAccessRsp(p) => p.read_replace(reader, payload_length),
+            TesterPresentReq(p) => p.read_replace(reader, payload_length),
+            TesterPresentRsp(p) => p.read_replace(reader, payload_length),
             TransferDataReq(p) => p.read_replace(reader, payload_length),
             TransferDataRsp(p) => p.read_replace(reader, payload_length),
             TransferExitReq(p) => p.read_replace(reader, payload_length),
             TransferExitRsp(p) => p.read_replace(reader, payload_length),
             WriteDIDReq(p) => p.read_replace(reader, payload_length),
             WriteDIDRsp(p) => p.read_replace(reader, payload_length),
+            WriteMemoryByAddressReq(p) => p.read_replace(reader, payload_length),
+            WriteMemoryByAddressRsp(p) => p.read_replace(reader, payload_length),
         }
     }
 
@@ -189,20 +416,52 @@ impl UdsMessage {
         match self {
             Nrc(p) => p.write(writer),
             RawUds(p) => p.write(writer),
+            ClearDTCReq(p) => p.write(writer),
+            ClearDTCRsp(p) => p.write(writer),
+            CommunicationControlReq(p) => p.write(writer),
+            CommunicationControlRsp(p) => p.write(writer),
+            ControlDTCSettingReq(p) => p.write(writer),
+            ControlDTCSettingRsp(p) => p.write(writer),
+            DiagnosticSessionControlReq(p) => p.write(writer),
+            DiagnosticSessionControlRsp(p) => p.write(writer),
+            DynamicallyDefineDIDReq(p) => p.write(writer),
+            DynamicallyDefineDIDRsp(p) => p.write(writer),
+            EcuResetReq(p) => p.write(writer),
+            EcuResetRsp(p) => p.write(writer),
+            IoControlReq(p) => p.write(writer),
+            IoControlRsp(p) => p.write(writer),
+            LinkControlReq(p) => p.write(writer),
+            LinkControlRsp(p) => p.write(writer),
             ReadDIDReq(p) => p.write(writer),
             ReadDIDRsp(p) => p.write(writer),
             ReadDTCReq(p) => p.write(writer),
             ReadDTCRsp(p) => p.write(writer),
+            ReadMemoryByAddressReq(p) => p.write(writer),
+            ReadMemoryByAddressRsp(p) => p.write(writer),
+            ReadPeriodicDIDReq(p) => p.write(writer),
+            ReadPeriodicDIDRsp(p) => p.write(writer),
             RequestDownloadReq(p) => p.write(writer),
             RequestDownloadRsp(p) => p.write(writer),
             RequestFileTransferReq(p) => p.write(writer),
             RequestFileTransferRsp(p) => p.write(writer),
+            RequestUploadReq(p) => p.write(writer),
+            RequestUploadRsp(p) => p.write(writer),
+            ResponseOnEventReq(p) => p.write(writer),
+            ResponseOnEventRsp(p) => p.write(writer),
+            RoutineControlReq(p) => p.write(writer),
+            RoutineControlRsp(p) => p.write(writer),
+            SecurityAccessReq(p) => p.write(writer),
+            SecurityAccessRsp(p) => p.write(writer),
+            TesterPresentReq(p) => p.write(writer),
+            TesterPresentRsp(p) => p.write(writer),
             TransferDataReq(p) => p.write(writer),
             TransferDataRsp(p) => p.write(writer),
             TransferExitReq(p) => p.write(writer),
             TransferExitRsp(p) => p.write(writer),
             WriteDIDReq(p) => p.write(writer),
             WriteDIDRsp(p) => p.write(writer),
+            WriteMemoryByAddressReq(p) => p.write(writer),
+            WriteMemoryByAddressRsp(p) => p.write(writer),
         }
     }
 }
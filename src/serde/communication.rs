@@ -0,0 +1,188 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::communication::*;
+use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+impl From<CommunicationControlType> for u8 {
+    fn from(item: CommunicationControlType) -> Self {
+        use CommunicationControlType::*;
+        match item {
+            EnableRxAndTx => 0x00,
+            EnableRxAndDisableTx => 0x01,
+            DisableRxAndEnableTx => 0x02,
+            DisableRxAndTx => 0x03,
+            EnableRxAndDisableTxWithEnhancedAddressInformation => 0x04,
+            EnableRxAndTxWithEnhancedAddressInformation => 0x05,
+            VehicleManufacturerSpecific(v) | SystemSupplierSpecific(v) | Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for CommunicationControlType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x00 => Self::EnableRxAndTx,
+            0x01 => Self::EnableRxAndDisableTx,
+            0x02 => Self::DisableRxAndEnableTx,
+            0x03 => Self::DisableRxAndTx,
+            0x04 => Self::EnableRxAndDisableTxWithEnhancedAddressInformation,
+            0x05 => Self::EnableRxAndTxWithEnhancedAddressInformation,
+            0x40..=0x5f => Self::VehicleManufacturerSpecific(item),
+            0x60..=0x7e => Self::SystemSupplierSpecific(item),
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl CommunicationControlType {
+    fn has_node_identification_number(self) -> bool {
+        matches!(
+            self,
+            Self::EnableRxAndDisableTxWithEnhancedAddressInformation
+                | Self::EnableRxAndTxWithEnhancedAddressInformation
+        )
+    }
+}
+
+impl From<CommunicationType> for u8 {
+    fn from(item: CommunicationType) -> Self {
+        let subnet = match item.subnet {
+            CommunicationSubnet::AllNetworks => 0x0,
+            CommunicationSubnet::Network(n) => n & 0x0f,
+            CommunicationSubnet::ReceivingNetwork => 0xf,
+        };
+        (subnet << 4)
+            | ((item.reserved & 0x03) << 2)
+            | (u8::from(item.network_management_messages) << 1)
+            | u8::from(item.normal_messages)
+    }
+}
+
+impl From<u8> for CommunicationType {
+    fn from(item: u8) -> Self {
+        let subnet = match item >> 4 {
+            0x0 => CommunicationSubnet::AllNetworks,
+            0xf => CommunicationSubnet::ReceivingNetwork,
+            n => CommunicationSubnet::Network(n),
+        };
+        Self {
+            normal_messages: (item & 0x01) != 0,
+            network_management_messages: (item & 0x02) != 0,
+            subnet,
+            reserved: (item >> 2) & 0x03,
+        }
+    }
+}
+
+impl CommunicationType {
+    fn check(self) -> Result<(), UdsError> {
+        if let CommunicationSubnet::Network(n) = self.subnet {
+            if !(0x1..=0xe).contains(&n) {
+                return Err(UdsError::EncodingError {
+                    msg: format!("Subnet number {n} should be between 1 and 14"),
+                });
+            }
+        }
+        if self.reserved > 0x03 {
+            return Err(UdsError::EncodingError {
+                msg: format!("Reserved bits 0x{:x} should fit in 2 bits", self.reserved),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Payload for CommunicationControlReq {
+    fn length(&self) -> usize {
+        if self.control_type.has_node_identification_number() {
+            4
+        } else {
+            2
+        }
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 2 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 2u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.control_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        if payload_length != self.length() {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: self.length() as u32,
+            });
+        }
+        self.communication_type = reader.read_u8()?.into();
+        self.communication_type.check()?;
+        self.node_identification_number = if self.control_type.has_node_identification_number() {
+            Some(reader.read_u16::<BigEndian>()?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        self.communication_type.check()?;
+        let mut sub: u8 = self.control_type.into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        writer.write_u8(self.communication_type.into())?;
+        if self.control_type.has_node_identification_number() {
+            let node = self
+                .node_identification_number
+                .ok_or_else(|| UdsError::EncodingError {
+                    msg: "Enhanced address control type without node identification number"
+                        .to_string(),
+                })?;
+            writer.write_u16::<BigEndian>(node)?;
+        }
+        Ok(())
+    }
+}
+
+impl Payload for CommunicationControlRsp {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        self.control_type = reader.read_u8()?.into();
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(self.control_type.into())?;
+        Ok(())
+    }
+}
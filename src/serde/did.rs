@@ -1,12 +1,19 @@
-use super::Payload;
-use crate::proto::did::{ReadDIDReq, ReadDIDRsp, WriteDIDReq, WriteDIDRsp};
-use crate::UdsError::{self, PayloadLengthTooShort};
+use super::transfers::{
+    read_address_and_length_format, read_sized, write_address_and_length_format, write_sized,
+};
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::did::{
+    DidSourceElement, DynamicDidDefinition, DynamicDidDefinitionType, DynamicallyDefineDIDReq,
+    DynamicallyDefineDIDRsp, MemorySourceElement, PeriodicDataFrame, ReadDIDReq, ReadDIDRsp,
+    ReadPeriodicDIDReq, ReadPeriodicDIDRsp, TransmissionMode, WriteDIDReq, WriteDIDRsp,
+};
+use crate::UdsError::{self, EncodingError, PayloadLengthTooShort};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::io::{Read, Write};
 
 impl Payload for ReadDIDReq {
     fn length(&self) -> usize {
-        2
+        2 * self.dids.len()
     }
 
     fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
@@ -18,18 +25,33 @@ impl Payload for ReadDIDReq {
         reader: &mut T,
         payload_length: usize,
     ) -> Result<(), UdsError> {
-        if payload_length != 2 {
+        if payload_length < 2 {
             return Err(PayloadLengthTooShort {
                 value: payload_length as u32,
                 expected: 2u32,
             });
         }
-        self.did = reader.read_u16::<BigEndian>()?;
+        if !payload_length.is_multiple_of(2) {
+            return Err(EncodingError {
+                msg: "ReadDIDReq is not a list of DIDs".to_string(),
+            });
+        }
+        self.dids.clear();
+        for _ in 0..payload_length / 2 {
+            self.dids.push(reader.read_u16::<BigEndian>()?);
+        }
         Ok(())
     }
 
     fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
-        writer.write_u16::<BigEndian>(self.did)?;
+        if self.dids.is_empty() {
+            return Err(EncodingError {
+                msg: "ReadDIDReq needs at least one DID".to_string(),
+            });
+        }
+        for did in &self.dids {
+            writer.write_u16::<BigEndian>(*did)?;
+        }
         Ok(())
     }
 }
@@ -37,6 +59,11 @@ impl Payload for ReadDIDReq {
 impl Payload for ReadDIDRsp {
     fn length(&self) -> usize {
         2 + self.user_data.len()
+            + self
+                .other_records
+                .iter()
+                .map(|(_, value)| 2 + value.len())
+                .sum::<usize>()
     }
 
     fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
@@ -57,12 +84,17 @@ impl Payload for ReadDIDRsp {
         self.did = reader.read_u16::<BigEndian>()?;
         self.user_data.resize(payload_length - 2, 0u8);
         reader.read_exact(&mut self.user_data)?;
+        self.other_records.clear();
         Ok(())
     }
 
     fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
         writer.write_u16::<BigEndian>(self.did)?;
         writer.write_all(&self.user_data)?;
+        for (did, value) in &self.other_records {
+            writer.write_u16::<BigEndian>(*did)?;
+            writer.write_all(value)?;
+        }
         Ok(())
     }
 }
@@ -129,3 +161,361 @@ impl Payload for WriteDIDRsp {
         Ok(())
     }
 }
+
+impl From<DynamicDidDefinitionType> for u8 {
+    fn from(item: DynamicDidDefinitionType) -> Self {
+        match item {
+            DynamicDidDefinitionType::DefineByIdentifier => 0x01,
+            DynamicDidDefinitionType::DefineByMemoryAddress => 0x02,
+            DynamicDidDefinitionType::ClearDynamicallyDefinedDataIdentifier => 0x03,
+            DynamicDidDefinitionType::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for DynamicDidDefinitionType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::DefineByIdentifier,
+            0x02 => Self::DefineByMemoryAddress,
+            0x03 => Self::ClearDynamicallyDefinedDataIdentifier,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for DynamicallyDefineDIDReq {
+    fn length(&self) -> usize {
+        match &self.definition {
+            DynamicDidDefinition::DefineByIdentifier { sources, .. } => 3 + 4 * sources.len(),
+            DynamicDidDefinition::DefineByMemoryAddress {
+                memory_size_bytes,
+                memory_address_bytes,
+                sources,
+                ..
+            } => {
+                4 + (usize::from(*memory_size_bytes) + usize::from(*memory_address_bytes))
+                    * sources.len()
+            }
+            DynamicDidDefinition::ClearDynamicallyDefinedDataIdentifier { did } => {
+                1 + 2 * usize::from(did.is_some())
+            }
+        }
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        let definition_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        self.definition = match definition_type {
+            DynamicDidDefinitionType::DefineByIdentifier => {
+                if payload_length < 7 {
+                    return Err(PayloadLengthTooShort {
+                        value: payload_length as u32,
+                        expected: 7u32,
+                    });
+                }
+                if !(payload_length - 3).is_multiple_of(4) {
+                    return Err(EncodingError {
+                        msg: "DynamicallyDefineDIDReq is not a list of DID sources".to_string(),
+                    });
+                }
+                let did = reader.read_u16::<BigEndian>()?;
+                let mut sources = vec![];
+                for _ in 0..(payload_length - 3) / 4 {
+                    sources.push(DidSourceElement {
+                        source_did: reader.read_u16::<BigEndian>()?,
+                        position: reader.read_u8()?,
+                        memory_size: reader.read_u8()?,
+                    });
+                }
+                DynamicDidDefinition::DefineByIdentifier { did, sources }
+            }
+            DynamicDidDefinitionType::DefineByMemoryAddress => {
+                if payload_length < 4 {
+                    return Err(PayloadLengthTooShort {
+                        value: payload_length as u32,
+                        expected: 4u32,
+                    });
+                }
+                let did = reader.read_u16::<BigEndian>()?;
+                let (memory_address_bytes, memory_size_bytes) =
+                    read_address_and_length_format(reader)?;
+                let source_length =
+                    usize::from(memory_address_bytes) + usize::from(memory_size_bytes);
+                if payload_length < 4 + source_length {
+                    return Err(PayloadLengthTooShort {
+                        value: payload_length as u32,
+                        expected: (4 + source_length) as u32,
+                    });
+                }
+                if source_length == 0 || !(payload_length - 4).is_multiple_of(source_length) {
+                    return Err(EncodingError {
+                        msg: "DynamicallyDefineDIDReq is not a list of memory sources".to_string(),
+                    });
+                }
+                let mut sources = vec![];
+                for _ in 0..(payload_length - 4) / source_length {
+                    sources.push(MemorySourceElement {
+                        memory_address: read_sized(reader, memory_address_bytes.into())?,
+                        memory_size: read_sized(reader, memory_size_bytes.into())?,
+                    });
+                }
+                DynamicDidDefinition::DefineByMemoryAddress {
+                    did,
+                    memory_size_bytes,
+                    memory_address_bytes,
+                    sources,
+                }
+            }
+            DynamicDidDefinitionType::ClearDynamicallyDefinedDataIdentifier => {
+                let did = match payload_length {
+                    1 => None,
+                    3 => Some(reader.read_u16::<BigEndian>()?),
+                    _ => {
+                        return Err(EncodingError {
+                            msg: "DynamicallyDefineDIDReq clears a single DID".to_string(),
+                        })
+                    }
+                };
+                DynamicDidDefinition::ClearDynamicallyDefinedDataIdentifier { did }
+            }
+            DynamicDidDefinitionType::Reserved(sub) => {
+                return Err(EncodingError {
+                    msg: format!("Unknown DynamicallyDefineDIDReq sub-function 0x{sub:02x}"),
+                })
+            }
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub: u8 = self.definition.definition_type().into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        match &self.definition {
+            DynamicDidDefinition::DefineByIdentifier { did, sources } => {
+                if sources.is_empty() {
+                    return Err(EncodingError {
+                        msg: "DynamicallyDefineDIDReq needs at least one DID source".to_string(),
+                    });
+                }
+                writer.write_u8(sub)?;
+                writer.write_u16::<BigEndian>(*did)?;
+                for source in sources {
+                    writer.write_u16::<BigEndian>(source.source_did)?;
+                    writer.write_u8(source.position)?;
+                    writer.write_u8(source.memory_size)?;
+                }
+            }
+            DynamicDidDefinition::DefineByMemoryAddress {
+                did,
+                memory_size_bytes,
+                memory_address_bytes,
+                sources,
+            } => {
+                if sources.is_empty() {
+                    return Err(EncodingError {
+                        msg: "DynamicallyDefineDIDReq needs at least one memory source".to_string(),
+                    });
+                }
+                writer.write_u8(sub)?;
+                writer.write_u16::<BigEndian>(*did)?;
+                write_address_and_length_format(writer, *memory_address_bytes, *memory_size_bytes)?;
+                for source in sources {
+                    write_sized(
+                        writer,
+                        source.memory_address,
+                        (*memory_address_bytes).into(),
+                    )?;
+                    write_sized(writer, source.memory_size, (*memory_size_bytes).into())?;
+                }
+            }
+            DynamicDidDefinition::ClearDynamicallyDefinedDataIdentifier { did } => {
+                writer.write_u8(sub)?;
+                if let Some(did) = did {
+                    writer.write_u16::<BigEndian>(*did)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Payload for DynamicallyDefineDIDRsp {
+    fn length(&self) -> usize {
+        1 + 2 * usize::from(self.did.is_some())
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 && payload_length != 3 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 3u32,
+            });
+        }
+        self.definition_type = reader.read_u8()?.into();
+        self.did = if payload_length == 3 {
+            Some(reader.read_u16::<BigEndian>()?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(self.definition_type.into())?;
+        if let Some(did) = self.did {
+            writer.write_u16::<BigEndian>(did)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<TransmissionMode> for u8 {
+    fn from(item: TransmissionMode) -> Self {
+        match item {
+            TransmissionMode::SendAtSlowRate => 0x01,
+            TransmissionMode::SendAtMediumRate => 0x02,
+            TransmissionMode::SendAtFastRate => 0x03,
+            TransmissionMode::StopSending => 0x04,
+            TransmissionMode::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for TransmissionMode {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::SendAtSlowRate,
+            0x02 => Self::SendAtMediumRate,
+            0x03 => Self::SendAtFastRate,
+            0x04 => Self::StopSending,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for ReadPeriodicDIDReq {
+    fn length(&self) -> usize {
+        1 + self.periodic_ids.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        self.transmission_mode = reader.read_u8()?.into();
+        self.periodic_ids.resize(payload_length - 1, 0u8);
+        reader.read_exact(&mut self.periodic_ids)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        if self.periodic_ids.is_empty() && self.transmission_mode != TransmissionMode::StopSending {
+            return Err(EncodingError {
+                msg: "ReadPeriodicDIDReq needs at least one periodic DID".to_string(),
+            });
+        }
+        writer.write_u8(self.transmission_mode.into())?;
+        writer.write_all(&self.periodic_ids)?;
+        Ok(())
+    }
+}
+
+impl Payload for ReadPeriodicDIDRsp {
+    fn length(&self) -> usize {
+        self.frame.as_ref().map_or(0, PeriodicDataFrame::length)
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        self.frame = if payload_length == 0 {
+            None
+        } else {
+            Some(PeriodicDataFrame::read(reader, payload_length)?)
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        if let Some(frame) = &self.frame {
+            frame.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Payload for PeriodicDataFrame {
+    fn length(&self) -> usize {
+        1 + self.data.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        self.periodic_id = reader.read_u8()?;
+        self.data.resize(payload_length - 1, 0u8);
+        reader.read_exact(&mut self.data)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(self.periodic_id)?;
+        writer.write_all(&self.data)?;
+        Ok(())
+    }
+}
@@ -1,9 +1,219 @@
 use super::deserializer::DecodeError;
-use super::Payload;
-use crate::proto::dtc::{DTCReqSubfunction, DTCRspSubfunction, ReadDTCReq, ReadDTCRsp};
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::did::DidLengthSource;
+use crate::proto::dtc::{
+    ClearDTCReq, ClearDTCRsp, ControlDTCSettingReq, ControlDTCSettingRsp, DTCReqSubfunction,
+    DTCRspSubfunction, DTCSettingType, DidSnapshot, DtcAndStatusRecord, DtcFormat,
+    DtcSettingOption, DtcSnapshotRecords, DtcStoredDataRecord, ReadDTCReq, ReadDTCRsp,
+    RecordNumberAndIdentifierAndSnapshot, ResponseDTCSnapshotRecordByDTCNumber,
+    ResponseDTCStoredDataByRecordNumber, ResponseUserDefMemoryDTCSnapshotRecordByDTCNumber,
+};
 use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::io::{Read, Write};
 
+impl Payload for ClearDTCReq {
+    fn length(&self) -> usize {
+        3 + usize::from(self.memory.is_some())
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 3 && payload_length != 4 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 3u32,
+            });
+        }
+        reader.read_exact(&mut self.group.dtc)?;
+        self.memory = if payload_length == 4 {
+            Some(reader.read_u8()?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_all(&self.group.dtc)?;
+        if let Some(memory) = self.memory {
+            writer.write_u8(memory)?;
+        }
+        Ok(())
+    }
+}
+
+impl Payload for ClearDTCRsp {
+    fn length(&self) -> usize {
+        0
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        _reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 0 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 0u32,
+            });
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, _writer: &mut T) -> Result<(), UdsError> {
+        Ok(())
+    }
+}
+
+impl From<DTCSettingType> for u8 {
+    fn from(item: DTCSettingType) -> Self {
+        match item {
+            DTCSettingType::On => 0x01,
+            DTCSettingType::Off => 0x02,
+            DTCSettingType::VehicleManufacturerSpecific(v)
+            | DTCSettingType::SystemSupplierSpecific(v)
+            | DTCSettingType::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for DTCSettingType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::On,
+            0x02 => Self::Off,
+            0x40..=0x5f => Self::VehicleManufacturerSpecific(item),
+            0x60..=0x7e => Self::SystemSupplierSpecific(item),
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl From<DtcFormat> for u8 {
+    fn from(item: DtcFormat) -> Self {
+        match item {
+            DtcFormat::Iso15031 => 0x00,
+            DtcFormat::Iso14229 => 0x01,
+            DtcFormat::SaeJ1939 => 0x02,
+            DtcFormat::Iso11992 => 0x03,
+            DtcFormat::SaeJ2012WwhObd => 0x04,
+            DtcFormat::Reserved(value) => value,
+        }
+    }
+}
+
+impl From<u8> for DtcFormat {
+    fn from(item: u8) -> Self {
+        match item {
+            0x00 => Self::Iso15031,
+            0x01 => Self::Iso14229,
+            0x02 => Self::SaeJ1939,
+            0x03 => Self::Iso11992,
+            0x04 => Self::SaeJ2012WwhObd,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for ControlDTCSettingReq {
+    fn length(&self) -> usize {
+        1 + match &self.option {
+            DtcSettingOption::None => 0,
+            DtcSettingOption::Dtcs(dtcs) => 3 * dtcs.len(),
+            DtcSettingOption::Raw(record) => record.len(),
+        }
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.setting_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        self.option = if payload_length == 1 {
+            DtcSettingOption::None
+        } else {
+            let mut record = vec![0u8; payload_length - 1];
+            reader.read_exact(&mut record)?;
+            DtcSettingOption::Raw(record)
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub: u8 = self.setting_type.into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        match &self.option {
+            DtcSettingOption::None => {}
+            DtcSettingOption::Dtcs(dtcs) => {
+                for dtc in dtcs {
+                    writer.write_all(&dtc.dtc)?;
+                }
+            }
+            DtcSettingOption::Raw(record) => writer.write_all(record)?,
+        }
+        Ok(())
+    }
+}
+
+impl Payload for ControlDTCSettingRsp {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        self.setting_type = reader.read_u8()?.into();
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(self.setting_type.into())?;
+        Ok(())
+    }
+}
+
 impl Payload for ReadDTCReq {
     fn length(&self) -> usize {
         1
@@ -78,6 +288,151 @@ impl Payload for ReadDTCRsp {
     }
 }
 
+impl ReadDTCRsp {
+    /// Decode the DTC responses holding DID snapshots, i.e. `0x04`, `0x05` and
+    /// `0x18`, with the DID lengths of `lengths`
+    ///
+    /// Returns `None` for the other sub-functions, and when the length of a DID
+    /// is unknown, both being left to the default decoding.
+    pub(crate) fn read_did_snapshots<S: DidLengthSource + ?Sized>(
+        data: &[u8],
+        lengths: &S,
+    ) -> Option<Result<Self, UdsError>> {
+        let (&sub, mut data) = data.split_first()?;
+        let sub = match sub {
+            0x04 => read_dtc_status(&mut data).and_then(|dtc_status| {
+                let mut dtc_snapshot_records = vec![];
+                while !data.is_empty() {
+                    let Some((record_number, number_identifiers, records)) =
+                        read_snapshot_record(&mut data, lengths)?
+                    else {
+                        return Ok(None);
+                    };
+                    dtc_snapshot_records.push(DtcSnapshotRecords {
+                        dtc_snapshot_record_number: record_number,
+                        dtc_snapshot_record_number_of_identifiers: number_identifiers,
+                        dtc_snapshot_record: records,
+                    });
+                }
+                Ok(Some(
+                    DTCRspSubfunction::ResponseDTCSnapshotRecordByDTCNumber(
+                        ResponseDTCSnapshotRecordByDTCNumber {
+                            dtc_status,
+                            dtc_snapshot_records,
+                        },
+                    ),
+                ))
+            }),
+            0x05 => {
+                let mut records = vec![];
+                let mut read_records = || {
+                    while !data.is_empty() {
+                        let record_number = data.read_u8()?;
+                        let dtc_status = read_dtc_status(&mut data)?;
+                        let number_identifiers = data.read_u8()?;
+                        let Some(data_records) =
+                            read_did_snapshots(&mut data, number_identifiers, lengths)?
+                        else {
+                            return Ok(false);
+                        };
+                        records.push(DtcStoredDataRecord {
+                            record_number,
+                            dtc_status,
+                            number_identifiers,
+                            data_records,
+                        });
+                    }
+                    Ok(true)
+                };
+                read_records().map(|complete| {
+                    complete.then_some(DTCRspSubfunction::ResponseDTCStoredDataByRecordNumber(
+                        ResponseDTCStoredDataByRecordNumber { records },
+                    ))
+                })
+            }
+            0x18 => {
+                let mut read_response = || {
+                    let memory = data.read_u8()?;
+                    let dtc_status = read_dtc_status(&mut data)?;
+                    let mut records = vec![];
+                    while !data.is_empty() {
+                        let Some((record_number, number_identifiers, snapshots)) =
+                            read_snapshot_record(&mut data, lengths)?
+                        else {
+                            return Ok(None);
+                        };
+                        records.push(RecordNumberAndIdentifierAndSnapshot {
+                            record_number,
+                            number_identifiers,
+                            records: snapshots,
+                        });
+                    }
+                    Ok(Some(ResponseUserDefMemoryDTCSnapshotRecordByDTCNumber {
+                        memory,
+                        dtc_status,
+                        records,
+                    }))
+                };
+                read_response().map(|rsp| {
+                    rsp.map(DTCRspSubfunction::ResponseUserDefMemoryDTCSnapshotRecordByDTCNumber)
+                })
+            }
+            _ => return None,
+        };
+        sub.transpose().map(|sub| sub.map(|sub| Self { sub }))
+    }
+}
+
+fn read_dtc_status(data: &mut &[u8]) -> Result<DtcAndStatusRecord, UdsError> {
+    let mut dtc = [0u8; 3];
+    data.read_exact(&mut dtc)?;
+    Ok(DtcAndStatusRecord {
+        dtc: dtc.into(),
+        status: data.read_u8()?.into(),
+    })
+}
+
+/// Reads a snapshot record, returned as (record number, number of
+/// identifiers, DIDs), or `None` if the length of a DID is unknown
+fn read_snapshot_record<S: DidLengthSource + ?Sized>(
+    data: &mut &[u8],
+    lengths: &S,
+) -> Result<Option<(u8, u8, Vec<DidSnapshot>)>, UdsError> {
+    let record_number = data.read_u8()?;
+    let number_identifiers = data.read_u8()?;
+    let snapshots = read_did_snapshots(data, number_identifiers, lengths)?;
+    Ok(snapshots.map(|snapshots| (record_number, number_identifiers, snapshots)))
+}
+
+/// Reads `number_identifiers` DIDs with their value, or `None` if the length
+/// of a DID is unknown, as the records following it couldn't be split
+fn read_did_snapshots<S: DidLengthSource + ?Sized>(
+    data: &mut &[u8],
+    number_identifiers: u8,
+    lengths: &S,
+) -> Result<Option<Vec<DidSnapshot>>, UdsError> {
+    let mut snapshots = vec![];
+    for _ in 0..number_identifiers {
+        let did = data.read_u16::<BigEndian>()?;
+        let Some(length) = lengths.did_length(did) else {
+            return Ok(None);
+        };
+        if data.len() < length {
+            return Err(PayloadLengthTooShort {
+                value: data.len() as u32,
+                expected: length as u32,
+            });
+        }
+        let (value, rest) = data.split_at(length);
+        snapshots.push(DidSnapshot {
+            did,
+            value: value.to_vec(),
+        });
+        *data = rest;
+    }
+    Ok(Some(snapshots))
+}
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -94,15 +449,15 @@ mod test {
         ];
         let msg = uds_read(&mut Cursor::new(req), req.len()).unwrap();
         let supported = dtc::ResponseSupportedDTC {
-            availability_mask: 0xff,
+            availability_mask: 0xff.into(),
             dtcs: vec![
                 DtcAndStatusRecord {
                     dtc: dtc::Dtc::new(0xea, 0x19, 0x88),
-                    status: 0x00,
+                    status: 0x00.into(),
                 },
                 DtcAndStatusRecord {
                     dtc: dtc::Dtc::new(0xad, 0xa6, 0x11),
-                    status: 0x50,
+                    status: 0x50.into(),
                 },
             ],
         };
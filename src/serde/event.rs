@@ -0,0 +1,337 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::event::{
+    ComparisonLogic, ResponseOnEventReq, ResponseOnEventRsp, RoeEvent, ValueComparison,
+};
+use crate::UdsError::{self, EncodingError, PayloadLengthTooShort};
+use crate::UdsMessage;
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+/// Sub-function bit asking the server to keep the event across power cycles
+const STORE_EVENT: u8 = 0x40;
+/// Sub-function bits of the event type
+const EVENT_TYPE_MASK: u8 = 0x3f;
+
+impl From<ComparisonLogic> for u8 {
+    fn from(item: ComparisonLogic) -> Self {
+        match item {
+            ComparisonLogic::LessThan => 0x01,
+            ComparisonLogic::LargerThan => 0x02,
+            ComparisonLogic::Equal => 0x03,
+            ComparisonLogic::NotEqual => 0x04,
+            ComparisonLogic::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for ComparisonLogic {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::LessThan,
+            0x02 => Self::LargerThan,
+            0x03 => Self::Equal,
+            0x04 => Self::NotEqual,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+fn event_type(event: &RoeEvent) -> u8 {
+    match event {
+        RoeEvent::StopResponseOnEvent => 0x00,
+        RoeEvent::OnDTCStatusChange { .. } => 0x01,
+        RoeEvent::OnTimerInterrupt { .. } => 0x02,
+        RoeEvent::OnChangeOfDataIdentifier { .. } => 0x03,
+        RoeEvent::ReportActivatedEvents => 0x04,
+        RoeEvent::StartResponseOnEvent => 0x05,
+        RoeEvent::ClearResponseOnEvent => 0x06,
+        RoeEvent::OnComparisonOfValues(_) => 0x07,
+        RoeEvent::Reserved { event_type, .. } => *event_type,
+    }
+}
+
+/// Length of the `eventTypeRecord` of `event_type`, `None` if unknown
+fn event_record_length(event_type: u8) -> Option<usize> {
+    match event_type {
+        0x00 | 0x04..=0x06 => Some(0),
+        0x01 | 0x02 => Some(1),
+        0x03 => Some(2),
+        0x07 => Some(10),
+        _ => None,
+    }
+}
+
+/// Reads the `eventTypeRecord` of `event_type` among the `remaining` bytes
+fn read_event<R: Read>(
+    reader: &mut R,
+    event_type: u8,
+    remaining: usize,
+) -> Result<RoeEvent, UdsError> {
+    let length = event_record_length(event_type).unwrap_or(remaining);
+    if remaining < length {
+        return Err(PayloadLengthTooShort {
+            value: remaining as u32,
+            expected: length as u32,
+        });
+    }
+    Ok(match event_type {
+        0x00 => RoeEvent::StopResponseOnEvent,
+        0x01 => RoeEvent::OnDTCStatusChange {
+            mask: reader.read_u8()?.into(),
+        },
+        0x02 => RoeEvent::OnTimerInterrupt {
+            timer_rate: reader.read_u8()?,
+        },
+        0x03 => RoeEvent::OnChangeOfDataIdentifier {
+            did: reader.read_u16::<BigEndian>()?,
+        },
+        0x04 => RoeEvent::ReportActivatedEvents,
+        0x05 => RoeEvent::StartResponseOnEvent,
+        0x06 => RoeEvent::ClearResponseOnEvent,
+        0x07 => RoeEvent::OnComparisonOfValues(ValueComparison {
+            did: reader.read_u16::<BigEndian>()?,
+            logic: reader.read_u8()?.into(),
+            reference: reader.read_u32::<BigEndian>()?,
+            hysteresis: reader.read_u8()?,
+            localization: reader.read_u16::<BigEndian>()?,
+        }),
+        _ => {
+            let mut record = vec![0u8; length];
+            reader.read_exact(&mut record)?;
+            RoeEvent::Reserved { event_type, record }
+        }
+    })
+}
+
+fn write_event<W: Write>(writer: &mut W, event: &RoeEvent) -> Result<(), UdsError> {
+    match event {
+        RoeEvent::OnDTCStatusChange { mask } => writer.write_u8((*mask).into())?,
+        RoeEvent::OnTimerInterrupt { timer_rate } => writer.write_u8(*timer_rate)?,
+        RoeEvent::OnChangeOfDataIdentifier { did } => writer.write_u16::<BigEndian>(*did)?,
+        RoeEvent::OnComparisonOfValues(comparison) => {
+            writer.write_u16::<BigEndian>(comparison.did)?;
+            writer.write_u8(comparison.logic.into())?;
+            writer.write_u32::<BigEndian>(comparison.reference)?;
+            writer.write_u8(comparison.hysteresis)?;
+            writer.write_u16::<BigEndian>(comparison.localization)?;
+        }
+        RoeEvent::Reserved { record, .. } => writer.write_all(record)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn event_length(event: &RoeEvent) -> usize {
+    match event {
+        RoeEvent::Reserved { record, .. } => record.len(),
+        event => event_record_length(event_type(event)).unwrap_or_default(),
+    }
+}
+
+/// Reads the `serviceToRespondToRecord` of `event` in the `remaining` bytes
+fn read_service<R: Read>(
+    reader: &mut R,
+    event: &RoeEvent,
+    remaining: usize,
+) -> Result<Option<Box<UdsMessage>>, UdsError> {
+    if !event.is_setup() {
+        if remaining != 0 {
+            return Err(EncodingError {
+                msg: format!("{event:?} has no service to respond to"),
+            });
+        }
+        return Ok(None);
+    }
+    if remaining == 0 {
+        return Err(PayloadLengthTooShort {
+            value: 0,
+            expected: 1,
+        });
+    }
+    Ok(Some(Box::new(super::uds_read(reader, remaining)?)))
+}
+
+fn write_service<W: Write>(
+    writer: &mut W,
+    event: &RoeEvent,
+    service: Option<&UdsMessage>,
+) -> Result<(), UdsError> {
+    match service {
+        Some(service) if event.is_setup() => super::uds_write(writer, service),
+        None if !event.is_setup() => Ok(()),
+        Some(_) => Err(EncodingError {
+            msg: format!("{event:?} has no service to respond to"),
+        }),
+        None => Err(EncodingError {
+            msg: format!("{event:?} needs a service to respond to"),
+        }),
+    }
+}
+
+fn sub_function(event: &RoeEvent, store_event: bool) -> u8 {
+    let mut sub = event_type(event);
+    if store_event {
+        sub |= STORE_EVENT;
+    }
+    sub
+}
+
+impl Payload for ResponseOnEventReq {
+    fn length(&self) -> usize {
+        1 + usize::from(self.event_window_time.is_some())
+            + event_length(&self.event)
+            + self
+                .service_to_respond_to
+                .as_ref()
+                .map_or(0, |s| s.length())
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.store_event = (sub & STORE_EVENT) != 0;
+        let event_type = sub & EVENT_TYPE_MASK;
+        let mut remaining = payload_length - 1;
+        // The event window time is optional when starting, stopping or
+        // clearing the events
+        self.event_window_time = match event_type {
+            0x04 => None,
+            0x00 | 0x05 | 0x06 if remaining == 0 => None,
+            _ => {
+                if remaining == 0 {
+                    return Err(PayloadLengthTooShort {
+                        value: payload_length as u32,
+                        expected: 2u32,
+                    });
+                }
+                remaining -= 1;
+                Some(reader.read_u8()?)
+            }
+        };
+        self.event = read_event(reader, event_type, remaining)?;
+        remaining -= event_length(&self.event);
+        self.service_to_respond_to = read_service(reader, &self.event, remaining)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub = sub_function(&self.event, self.store_event);
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        match (&self.event, self.event_window_time) {
+            (RoeEvent::ReportActivatedEvents, Some(_)) => {
+                return Err(EncodingError {
+                    msg: "ReportActivatedEvents has no event window time".to_string(),
+                })
+            }
+            (event, None) if event.is_setup() => {
+                return Err(EncodingError {
+                    msg: format!("{event:?} needs an event window time"),
+                })
+            }
+            (_, Some(window)) => writer.write_u8(window)?,
+            (_, None) => {}
+        }
+        write_event(writer, &self.event)?;
+        write_service(writer, &self.event, self.service_to_respond_to.as_deref())
+    }
+}
+
+impl Payload for ResponseOnEventRsp {
+    fn length(&self) -> usize {
+        2 + usize::from(self.event_window_time.is_some())
+            + event_length(&self.event)
+            + self
+                .service_to_respond_to
+                .as_ref()
+                .map_or(0, |s| s.length())
+            + self.activated_events.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 2 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 2u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.store_event = (sub & STORE_EVENT) != 0;
+        let event_type = sub & EVENT_TYPE_MASK;
+        self.number_of_events = reader.read_u8()?;
+        let mut remaining = payload_length - 2;
+        self.activated_events.clear();
+        if event_type == 0x04 {
+            self.event = RoeEvent::ReportActivatedEvents;
+            self.event_window_time = None;
+            self.service_to_respond_to = None;
+            self.activated_events.resize(remaining, 0u8);
+            reader.read_exact(&mut self.activated_events)?;
+            return Ok(());
+        }
+        self.event_window_time = match event_type {
+            0x00 | 0x05 | 0x06 if remaining == 0 => None,
+            _ => {
+                if remaining == 0 {
+                    return Err(PayloadLengthTooShort {
+                        value: payload_length as u32,
+                        expected: 3u32,
+                    });
+                }
+                remaining -= 1;
+                Some(reader.read_u8()?)
+            }
+        };
+        self.event = read_event(reader, event_type, remaining)?;
+        remaining -= event_length(&self.event);
+        self.service_to_respond_to = read_service(reader, &self.event, remaining)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(sub_function(&self.event, self.store_event))?;
+        writer.write_u8(self.number_of_events)?;
+        if self.event == RoeEvent::ReportActivatedEvents {
+            writer.write_all(&self.activated_events)?;
+            return Ok(());
+        }
+        if !self.activated_events.is_empty() {
+            return Err(EncodingError {
+                msg: "Only ReportActivatedEvents has activated events".to_string(),
+            });
+        }
+        if let Some(window) = self.event_window_time {
+            writer.write_u8(window)?;
+        } else if self.event.is_setup() {
+            return Err(EncodingError {
+                msg: format!("{:?} needs an event window time", self.event),
+            });
+        }
+        write_event(writer, &self.event)?;
+        write_service(writer, &self.event, self.service_to_respond_to.as_deref())
+    }
+}
@@ -0,0 +1,116 @@
+use super::Payload;
+use crate::proto::io::{IoControlParameter, IoControlReq, IoControlRsp};
+use crate::UdsError::{self, EncodingError, PayloadLengthTooShort};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+impl From<IoControlParameter> for u8 {
+    fn from(item: IoControlParameter) -> Self {
+        match item {
+            IoControlParameter::ReturnControlToEcu => 0x00,
+            IoControlParameter::ResetToDefault => 0x01,
+            IoControlParameter::FreezeCurrentState => 0x02,
+            IoControlParameter::ShortTermAdjustment => 0x03,
+            IoControlParameter::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for IoControlParameter {
+    fn from(item: u8) -> Self {
+        match item {
+            0x00 => Self::ReturnControlToEcu,
+            0x01 => Self::ResetToDefault,
+            0x02 => Self::FreezeCurrentState,
+            0x03 => Self::ShortTermAdjustment,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for IoControlReq {
+    fn length(&self) -> usize {
+        3 + self.control_state.len() + self.control_enable_mask.as_ref().map_or(0, Vec::len)
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 3 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 3u32,
+            });
+        }
+        self.did = reader.read_u16::<BigEndian>()?;
+        self.parameter = reader.read_u8()?.into();
+        let mut data = vec![0u8; payload_length - 3];
+        reader.read_exact(&mut data)?;
+        if self.parameter == IoControlParameter::ShortTermAdjustment {
+            self.control_state = data;
+            self.control_enable_mask = None;
+        } else {
+            self.control_state.clear();
+            self.control_enable_mask = (!data.is_empty()).then_some(data);
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        if self.parameter != IoControlParameter::ShortTermAdjustment
+            && !self.control_state.is_empty()
+        {
+            return Err(EncodingError {
+                msg: format!("{:?} has no control state", self.parameter),
+            });
+        }
+        writer.write_u16::<BigEndian>(self.did)?;
+        writer.write_u8(self.parameter.into())?;
+        writer.write_all(&self.control_state)?;
+        if let Some(mask) = &self.control_enable_mask {
+            writer.write_all(mask)?;
+        }
+        Ok(())
+    }
+}
+
+impl Payload for IoControlRsp {
+    fn length(&self) -> usize {
+        3 + self.control_state.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 3 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 3u32,
+            });
+        }
+        self.did = reader.read_u16::<BigEndian>()?;
+        self.parameter = reader.read_u8()?.into();
+        self.control_state.resize(payload_length - 3, 0u8);
+        reader.read_exact(&mut self.control_state)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u16::<BigEndian>(self.did)?;
+        writer.write_u8(self.parameter.into())?;
+        writer.write_all(&self.control_state)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::link::*;
+use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+impl From<LinkControlType> for u8 {
+    fn from(item: LinkControlType) -> Self {
+        use LinkControlType::*;
+        match item {
+            VerifyModeTransitionWithFixedParameter => 0x01,
+            VerifyModeTransitionWithSpecificParameter => 0x02,
+            TransitionMode => 0x03,
+            VehicleManufacturerSpecific(v) | SystemSupplierSpecific(v) | Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for LinkControlType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::VerifyModeTransitionWithFixedParameter,
+            0x02 => Self::VerifyModeTransitionWithSpecificParameter,
+            0x03 => Self::TransitionMode,
+            0x40..=0x5f => Self::VehicleManufacturerSpecific(item),
+            0x60..=0x7e => Self::SystemSupplierSpecific(item),
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl LinkControlType {
+    fn baudrate_length(self) -> usize {
+        match self {
+            Self::VerifyModeTransitionWithFixedParameter => 1,
+            Self::VerifyModeTransitionWithSpecificParameter => 3,
+            _ => 0,
+        }
+    }
+}
+
+impl From<u8> for LinkBaudrate {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::Pc9600,
+            0x02 => Self::Pc19200,
+            0x03 => Self::Pc38400,
+            0x04 => Self::Pc57600,
+            0x05 => Self::Pc115200,
+            0x10 => Self::Can125000,
+            0x11 => Self::Can250000,
+            0x12 => Self::Can500000,
+            0x13 => Self::Can1000000,
+            0x20 => Self::ProgrammingSetup,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl LinkBaudrate {
+    /// Fixed baudrate identifier, `None` for a specific baudrate
+    fn identifier(self) -> Option<u8> {
+        match self {
+            Self::Pc9600 => Some(0x01),
+            Self::Pc19200 => Some(0x02),
+            Self::Pc38400 => Some(0x03),
+            Self::Pc57600 => Some(0x04),
+            Self::Pc115200 => Some(0x05),
+            Self::Can125000 => Some(0x10),
+            Self::Can250000 => Some(0x11),
+            Self::Can500000 => Some(0x12),
+            Self::Can1000000 => Some(0x13),
+            Self::ProgrammingSetup => Some(0x20),
+            Self::Reserved(v) => Some(v),
+            Self::Specific(_) => None,
+        }
+    }
+}
+
+impl Payload for LinkControlReq {
+    fn length(&self) -> usize {
+        1 + self.control_type.baudrate_length()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.control_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        if payload_length != self.length() {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: self.length() as u32,
+            });
+        }
+        self.baudrate = match self.control_type {
+            LinkControlType::VerifyModeTransitionWithFixedParameter => {
+                Some(reader.read_u8()?.into())
+            }
+            LinkControlType::VerifyModeTransitionWithSpecificParameter => {
+                Some(LinkBaudrate::Specific(reader.read_u24::<BigEndian>()?))
+            }
+            _ => None,
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub: u8 = self.control_type.into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        let invalid = || UdsError::EncodingError {
+            msg: format!(
+                "{:?} cannot be sent with baudrate {:?}",
+                self.control_type, self.baudrate
+            ),
+        };
+        writer.write_u8(sub)?;
+        match (self.control_type, self.baudrate) {
+            (LinkControlType::VerifyModeTransitionWithFixedParameter, Some(baudrate)) => {
+                writer.write_u8(baudrate.identifier().ok_or_else(invalid)?)?;
+            }
+            (
+                LinkControlType::VerifyModeTransitionWithSpecificParameter,
+                Some(LinkBaudrate::Specific(baudrate)),
+            ) if baudrate <= 0x00ff_ffff => writer.write_u24::<BigEndian>(baudrate)?,
+            (control_type, None) if control_type.baudrate_length() == 0 => {}
+            _ => return Err(invalid()),
+        }
+        Ok(())
+    }
+}
+
+impl Payload for LinkControlRsp {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        self.control_type = reader.read_u8()?.into();
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(self.control_type.into())?;
+        Ok(())
+    }
+}
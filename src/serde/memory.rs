@@ -0,0 +1,189 @@
+use super::transfers::{read_address_and_size, write_address_and_size};
+use super::Payload;
+use crate::proto::memory::*;
+use crate::UdsError::{self, *};
+use std::io::{Read, Write};
+
+fn memory_calculate_length(memory_address_bytes: u8, memory_size_bytes: u8) -> usize {
+    1 + memory_address_bytes as usize + memory_size_bytes as usize
+}
+
+impl Payload for ReadMemoryByAddressReq {
+    fn length(&self) -> usize {
+        memory_calculate_length(self.memory_address_bytes, self.memory_size_bytes)
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < memory_calculate_length(0, 0) {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: memory_calculate_length(0, 0) as u32,
+            });
+        }
+        (
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        ) = read_address_and_size(reader)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        write_address_and_size(
+            writer,
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        )
+    }
+}
+
+impl Payload for ReadMemoryByAddressRsp {
+    fn length(&self) -> usize {
+        self.data.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        self.data.resize(payload_length, 0);
+        reader.read_exact(&mut self.data)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_all(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Payload for WriteMemoryByAddressReq {
+    fn length(&self) -> usize {
+        memory_calculate_length(self.memory_address_bytes, self.memory_size_bytes) + self.data.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < memory_calculate_length(0, 0) {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: memory_calculate_length(0, 0) as u32,
+            });
+        }
+        (
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        ) = read_address_and_size(reader)?;
+        let header = memory_calculate_length(self.memory_address_bytes, self.memory_size_bytes);
+        if payload_length < header {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: header as u32,
+            });
+        }
+        if payload_length - header != self.memory_size {
+            return Err(EncodingError {
+                msg: format!(
+                    "WriteMemoryByAddressReq has {} bytes of data for a memory size of {}",
+                    payload_length - header,
+                    self.memory_size
+                ),
+            });
+        }
+        self.data.resize(self.memory_size, 0);
+        reader.read_exact(&mut self.data)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        if self.data.len() != self.memory_size {
+            return Err(EncodingError {
+                msg: format!(
+                    "WriteMemoryByAddressReq has {} bytes of data for a memory size of {}",
+                    self.data.len(),
+                    self.memory_size
+                ),
+            });
+        }
+        write_address_and_size(
+            writer,
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        )?;
+        writer.write_all(&self.data)?;
+        Ok(())
+    }
+}
+
+impl Payload for WriteMemoryByAddressRsp {
+    fn length(&self) -> usize {
+        memory_calculate_length(self.memory_address_bytes, self.memory_size_bytes)
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < memory_calculate_length(0, 0) {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: memory_calculate_length(0, 0) as u32,
+            });
+        }
+        (
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        ) = read_address_and_size(reader)?;
+        if payload_length != self.length() {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: self.length() as u32,
+            });
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        write_address_and_size(
+            writer,
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::reset::*;
+use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+impl From<ResetType> for u8 {
+    fn from(item: ResetType) -> Self {
+        match item {
+            ResetType::HardReset => 0x01,
+            ResetType::KeyOffOnReset => 0x02,
+            ResetType::SoftReset => 0x03,
+            ResetType::EnableRapidPowerShutDown => 0x04,
+            ResetType::DisableRapidPowerShutDown => 0x05,
+            ResetType::VehicleManufacturerSpecific(v)
+            | ResetType::SystemSupplierSpecific(v)
+            | ResetType::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for ResetType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::HardReset,
+            0x02 => Self::KeyOffOnReset,
+            0x03 => Self::SoftReset,
+            0x04 => Self::EnableRapidPowerShutDown,
+            0x05 => Self::DisableRapidPowerShutDown,
+            0x40..=0x5f => Self::VehicleManufacturerSpecific(item),
+            0x60..=0x7e => Self::SystemSupplierSpecific(item),
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for EcuResetReq {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.reset_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub: u8 = self.reset_type.into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        Ok(())
+    }
+}
+
+impl Payload for EcuResetRsp {
+    fn length(&self) -> usize {
+        1 + usize::from(self.power_down_time.is_some())
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        self.reset_type = reader.read_u8()?.into();
+        self.power_down_time = None;
+        if self.reset_type == ResetType::EnableRapidPowerShutDown {
+            if payload_length != 2 {
+                return Err(PayloadLengthTooShort {
+                    value: payload_length as u32,
+                    expected: 2u32,
+                });
+            }
+            self.power_down_time = Some(reader.read_u8()?);
+        } else if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        if self.power_down_time.is_some()
+            != (self.reset_type == ResetType::EnableRapidPowerShutDown)
+        {
+            return Err(UdsError::EncodingError {
+                msg: "Power down time is only present for EnableRapidPowerShutDown".to_string(),
+            });
+        }
+        writer.write_u8(self.reset_type.into())?;
+        if let Some(power_down_time) = self.power_down_time {
+            writer.write_u8(power_down_time)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::routine::*;
+use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+impl From<RoutineControlType> for u8 {
+    fn from(item: RoutineControlType) -> Self {
+        match item {
+            RoutineControlType::StartRoutine => 0x01,
+            RoutineControlType::StopRoutine => 0x02,
+            RoutineControlType::RequestRoutineResults => 0x03,
+            RoutineControlType::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for RoutineControlType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::StartRoutine,
+            0x02 => Self::StopRoutine,
+            0x03 => Self::RequestRoutineResults,
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for RoutineControlReq {
+    fn length(&self) -> usize {
+        3 + self.option_record.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 3 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 3u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.control_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        self.routine_identifier = reader.read_u16::<BigEndian>()?;
+        self.option_record.resize(payload_length - 3, 0u8);
+        reader.read_exact(&mut self.option_record)?;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub: u8 = self.control_type.into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        writer.write_u16::<BigEndian>(self.routine_identifier)?;
+        writer.write_all(&self.option_record)?;
+        Ok(())
+    }
+}
+
+impl Payload for RoutineControlRsp {
+    fn length(&self) -> usize {
+        3 + usize::from(self.routine_info.is_some()) + self.status_record.len()
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 3 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 3u32,
+            });
+        }
+        self.control_type = reader.read_u8()?.into();
+        self.routine_identifier = reader.read_u16::<BigEndian>()?;
+        self.routine_info = None;
+        self.status_record.clear();
+        if payload_length > 3 {
+            self.routine_info = Some(reader.read_u8()?);
+            self.status_record.resize(payload_length - 4, 0u8);
+            reader.read_exact(&mut self.status_record)?;
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(self.control_type.into())?;
+        writer.write_u16::<BigEndian>(self.routine_identifier)?;
+        match self.routine_info {
+            Some(info) => writer.write_u8(info)?,
+            None if !self.status_record.is_empty() => {
+                return Err(UdsError::EncodingError {
+                    msg: "routineStatusRecord requires a routineInfo".to_string(),
+                })
+            }
+            None => {}
+        }
+        writer.write_all(&self.status_record)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::security::*;
+use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+
+fn security_level(sub: u8) -> Result<u8, UdsError> {
+    match sub {
+        0x01..=0x7e => Ok(sub.div_ceil(2)),
+        _ => Err(UdsError::EncodingError {
+            msg: format!("Reserved security access type 0x{sub:02x}"),
+        }),
+    }
+}
+
+fn security_sub(level: u8, send_key: bool) -> Result<u8, UdsError> {
+    match level {
+        0x01..=0x3f => Ok(level * 2 - u8::from(!send_key)),
+        _ => Err(UdsError::EncodingError {
+            msg: "security level should be within 1..=63".to_string(),
+        }),
+    }
+}
+
+impl Payload for SecurityAccessReq {
+    fn length(&self) -> usize {
+        match &self.request {
+            SecurityAccessRequest::RequestSeed(data) | SecurityAccessRequest::SendKey(data) => {
+                1 + data.len()
+            }
+        }
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        let sub = sub & !SUPPRESS_POSITIVE_RESPONSE;
+        self.level = security_level(sub)?;
+        let mut data = vec![0u8; payload_length - 1];
+        reader.read_exact(&mut data)?;
+        self.request = if sub % 2 == 1 {
+            SecurityAccessRequest::RequestSeed(data)
+        } else {
+            SecurityAccessRequest::SendKey(data)
+        };
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let (send_key, data) = match &self.request {
+            SecurityAccessRequest::RequestSeed(data) => (false, data),
+            SecurityAccessRequest::SendKey(data) => (true, data),
+        };
+        let mut sub = security_sub(self.level, send_key)?;
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        writer.write_all(data)?;
+        Ok(())
+    }
+}
+
+impl Payload for SecurityAccessRsp {
+    fn length(&self) -> usize {
+        match &self.response {
+            SecurityAccessResponse::Seed(seed) => 1 + seed.len(),
+            SecurityAccessResponse::KeyAccepted => 1,
+        }
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length < 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.level = security_level(sub)?;
+        if sub % 2 == 1 {
+            let mut seed = vec![0u8; payload_length - 1];
+            reader.read_exact(&mut seed)?;
+            self.response = SecurityAccessResponse::Seed(seed);
+        } else {
+            if payload_length != 1 {
+                return Err(PayloadLengthTooShort {
+                    value: payload_length as u32,
+                    expected: 1u32,
+                });
+            }
+            self.response = SecurityAccessResponse::KeyAccepted;
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        match &self.response {
+            SecurityAccessResponse::Seed(seed) => {
+                writer.write_u8(security_sub(self.level, false)?)?;
+                writer.write_all(seed)?;
+            }
+            SecurityAccessResponse::KeyAccepted => {
+                writer.write_u8(security_sub(self.level, true)?)?;
+            }
+        }
+        Ok(())
+    }
+}
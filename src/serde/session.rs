@@ -0,0 +1,189 @@
+use super::{Payload, SUPPRESS_POSITIVE_RESPONSE};
+use crate::proto::session::*;
+use crate::UdsError::{self, PayloadLengthTooShort};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+use std::time::Duration;
+
+impl From<DiagnosticSessionType> for u8 {
+    fn from(item: DiagnosticSessionType) -> Self {
+        match item {
+            DiagnosticSessionType::DefaultSession => 0x01,
+            DiagnosticSessionType::ProgrammingSession => 0x02,
+            DiagnosticSessionType::ExtendedDiagnosticSession => 0x03,
+            DiagnosticSessionType::SafetySystemDiagnosticSession => 0x04,
+            DiagnosticSessionType::VehicleManufacturerSpecific(v)
+            | DiagnosticSessionType::SystemSupplierSpecific(v)
+            | DiagnosticSessionType::Reserved(v) => v,
+        }
+    }
+}
+
+impl From<u8> for DiagnosticSessionType {
+    fn from(item: u8) -> Self {
+        match item {
+            0x01 => Self::DefaultSession,
+            0x02 => Self::ProgrammingSession,
+            0x03 => Self::ExtendedDiagnosticSession,
+            0x04 => Self::SafetySystemDiagnosticSession,
+            0x40..=0x5f => Self::VehicleManufacturerSpecific(item),
+            0x60..=0x7e => Self::SystemSupplierSpecific(item),
+            _ => Self::Reserved(item),
+        }
+    }
+}
+
+impl Payload for DiagnosticSessionControlReq {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        self.session_type = (sub & !SUPPRESS_POSITIVE_RESPONSE).into();
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let mut sub: u8 = self.session_type.into();
+        if self.suppress_positive_response {
+            sub |= SUPPRESS_POSITIVE_RESPONSE;
+        }
+        writer.write_u8(sub)?;
+        Ok(())
+    }
+}
+
+impl Payload for DiagnosticSessionControlRsp {
+    fn length(&self) -> usize {
+        5
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 5 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 5u32,
+            });
+        }
+        self.session_type = reader.read_u8()?.into();
+        self.p2_server_max = Duration::from_millis(reader.read_u16::<BigEndian>()?.into());
+        self.p2_star_server_max =
+            Duration::from_millis(u64::from(reader.read_u16::<BigEndian>()?) * 10);
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        let p2 =
+            u16::try_from(self.p2_server_max.as_millis()).map_err(|_| UdsError::EncodingError {
+                msg: "p2_server_max should be at most 65535ms".to_string(),
+            })?;
+        let p2_star = u16::try_from(self.p2_star_server_max.as_millis() / 10).map_err(|_| {
+            UdsError::EncodingError {
+                msg: "p2_star_server_max should be at most 655350ms".to_string(),
+            }
+        })?;
+        writer.write_u8(self.session_type.into())?;
+        writer.write_u16::<BigEndian>(p2)?;
+        writer.write_u16::<BigEndian>(p2_star)?;
+        Ok(())
+    }
+}
+
+impl Payload for TesterPresentReq {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        if (sub & !SUPPRESS_POSITIVE_RESPONSE) != 0 {
+            return Err(UdsError::EncodingError {
+                msg: format!("Unexpected tester present sub-function 0x{sub:02x}"),
+            });
+        }
+        self.suppress_positive_response = (sub & SUPPRESS_POSITIVE_RESPONSE) != 0;
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        if self.suppress_positive_response {
+            writer.write_u8(SUPPRESS_POSITIVE_RESPONSE)?;
+        } else {
+            writer.write_u8(0)?;
+        }
+        Ok(())
+    }
+}
+
+impl Payload for TesterPresentRsp {
+    fn length(&self) -> usize {
+        1
+    }
+
+    fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
+        super::default_read(reader, payload_length)
+    }
+
+    fn read_replace<T: Read>(
+        &mut self,
+        reader: &mut T,
+        payload_length: usize,
+    ) -> Result<(), UdsError> {
+        if payload_length != 1 {
+            return Err(PayloadLengthTooShort {
+                value: payload_length as u32,
+                expected: 1u32,
+            });
+        }
+        let sub = reader.read_u8()?;
+        if sub != 0 {
+            return Err(UdsError::EncodingError {
+                msg: format!("Unexpected tester present sub-function 0x{sub:02x}"),
+            });
+        }
+        Ok(())
+    }
+
+    fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
+        writer.write_u8(0)?;
+        Ok(())
+    }
+}
@@ -4,51 +4,132 @@ use crate::UdsError::{self, *};
 use byteorder::{ReadBytesExt, WriteBytesExt};
 use std::io::{Read, Write};
 
-fn read_sized<R: Read>(reader: &mut R, nb_bytes: u16) -> Result<usize, UdsError> {
-    let mut vec = vec![0u8; nb_bytes as usize];
-    reader.read_exact(&mut vec)?;
-    let out: usize = match nb_bytes {
-        1 => {
-            let a: [u8; 1] = vec.try_into().unwrap();
-            Ok(u8::from_be_bytes(a) as usize)
-        }
-        2 => {
-            let a: [u8; 2] = vec.try_into().unwrap();
-            Ok(u16::from_be_bytes(a) as usize)
-        }
-        4 => {
-            let a: [u8; 4] = vec.try_into().unwrap();
-            Ok(u32::from_be_bytes(a) as usize)
-        }
-        _ => Err(UdsError::UnexpectedPayloadType { value: 0 }),
-    }?;
-    Ok(out)
+/// Largest address/size field handled by [`read_sized`] and [`write_sized`]
+const MAX_SIZED_BYTES: u16 = 5;
+
+pub(super) fn read_sized<R: Read>(reader: &mut R, nb_bytes: u16) -> Result<usize, UdsError> {
+    if !(1..=MAX_SIZED_BYTES).contains(&nb_bytes) {
+        return Err(UdsError::UnexpectedPayloadType { value: 0 });
+    }
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf[8 - nb_bytes as usize..])?;
+    Ok(u64::from_be_bytes(buf) as usize)
 }
 
-fn write_sized<W: Write>(writer: &mut W, val: usize, nb_bytes: u16) -> Result<(), UdsError> {
-    let vec = match nb_bytes {
-        1 => (val as u8).to_be_bytes().to_vec(),
-        2 => (val as u16).to_be_bytes().to_vec(),
-        4 => (val as u32).to_be_bytes().to_vec(),
-        _ => {
-            return Err(UdsError::EncodingError {
-                msg: "bytes should be 1,2 or 4".to_string(),
-            })
-        }
-    };
-    writer.write_all(&vec)?;
+pub(super) fn write_sized<W: Write>(
+    writer: &mut W,
+    val: usize,
+    nb_bytes: u16,
+) -> Result<(), UdsError> {
+    if !(1..=MAX_SIZED_BYTES).contains(&nb_bytes) {
+        return Err(UdsError::EncodingError {
+            msg: format!("bytes should be between 1 and {MAX_SIZED_BYTES}"),
+        });
+    }
+    if (val as u64) >> (8 * nb_bytes) != 0 {
+        return Err(UdsError::EncodingError {
+            msg: format!("0x{val:x} doesn't fit in {nb_bytes} bytes"),
+        });
+    }
+    writer.write_all(&(val as u64).to_be_bytes()[8 - nb_bytes as usize..])?;
     Ok(())
 }
 
-impl RequestDownloadReq {
-    fn transfer_req_calculate_length(memory_address_bytes: u8, memory_size_bytes: u8) -> usize {
-        1 + 1 + memory_address_bytes as usize + memory_size_bytes as usize
-    }
+fn read_data_format<R: Read>(reader: &mut R) -> Result<(u8, u8), UdsError> {
+    let compress_encrypt = reader.read_u8()?;
+    Ok((compress_encrypt >> 4, compress_encrypt & 0x0f))
+}
+
+fn write_data_format<W: Write>(
+    writer: &mut W,
+    compression_method: u8,
+    encryption_method: u8,
+) -> Result<(), UdsError> {
+    writer.write_u8((compression_method << 4) | (encryption_method & 0x0f))?;
+    Ok(())
+}
+
+/// Reads an `addressAndLengthFormatIdentifier`, returned as (address bytes,
+/// size bytes)
+pub(super) fn read_address_and_length_format<R: Read>(
+    reader: &mut R,
+) -> Result<(u8, u8), UdsError> {
+    let memory_bytes = reader.read_u8()?;
+    Ok((memory_bytes & 0x0f, memory_bytes >> 4))
 }
 
-impl Payload for RequestDownloadReq {
+pub(super) fn write_address_and_length_format<W: Write>(
+    writer: &mut W,
+    memory_address_bytes: u8,
+    memory_size_bytes: u8,
+) -> Result<(), UdsError> {
+    if memory_address_bytes > 0x0f || memory_size_bytes > 0x0f {
+        return Err(UdsError::EncodingError {
+            msg: "address and size byte counts should fit in 4 bits".to_string(),
+        });
+    }
+    writer.write_u8((memory_size_bytes << 4) | memory_address_bytes)?;
+    Ok(())
+}
+
+/// Reads an `addressAndLengthFormatIdentifier` followed by the memory address
+/// and size, returned as (address bytes, size bytes, address, size)
+pub(super) fn read_address_and_size<R: Read>(
+    reader: &mut R,
+) -> Result<(u8, u8, usize, usize), UdsError> {
+    let (memory_address_bytes, memory_size_bytes) = read_address_and_length_format(reader)?;
+    let memory_address = read_sized(reader, memory_address_bytes.into())?;
+    let memory_size = read_sized(reader, memory_size_bytes.into())?;
+    Ok((
+        memory_address_bytes,
+        memory_size_bytes,
+        memory_address,
+        memory_size,
+    ))
+}
+
+pub(super) fn write_address_and_size<W: Write>(
+    writer: &mut W,
+    memory_address_bytes: u8,
+    memory_size_bytes: u8,
+    memory_address: usize,
+    memory_size: usize,
+) -> Result<(), UdsError> {
+    write_address_and_length_format(writer, memory_address_bytes, memory_size_bytes)?;
+    write_sized(writer, memory_address, memory_address_bytes.into())?;
+    write_sized(writer, memory_size, memory_size_bytes.into())?;
+    Ok(())
+}
+
+fn transfer_req_calculate_length(memory_address_bytes: u8, memory_size_bytes: u8) -> usize {
+    1 + 1 + memory_address_bytes as usize + memory_size_bytes as usize
+}
+
+fn transfer_rsp_calculate_length(nb_bytes: u8) -> usize {
+    1 + nb_bytes as usize
+}
+
+/// Reads a `lengthFormatIdentifier` followed by the `maxNumberOfBlockLength`,
+/// returned as (block size bytes, block size)
+fn read_max_block_size<R: Read>(reader: &mut R) -> Result<(u8, usize), UdsError> {
+    let max_block_size_bytes = reader.read_u8()? >> 4;
+    let max_block_size = read_sized(reader, max_block_size_bytes.into())?;
+    Ok((max_block_size_bytes, max_block_size))
+}
+
+fn write_max_block_size<W: Write>(
+    writer: &mut W,
+    max_block_size_bytes: u8,
+    max_block_size: usize,
+) -> Result<(), UdsError> {
+    writer.write_u8(max_block_size_bytes << 4)?;
+    write_sized(writer, max_block_size, max_block_size_bytes.into())?;
+    Ok(())
+}
+
+impl Payload for TransferRequest {
     fn length(&self) -> usize {
-        Self::transfer_req_calculate_length(self.memory_address_bytes, self.memory_size_bytes)
+        transfer_req_calculate_length(self.memory_address_bytes, self.memory_size_bytes)
     }
 
     fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
@@ -60,50 +141,37 @@ impl Payload for RequestDownloadReq {
         reader: &mut T,
         payload_length: usize,
     ) -> Result<(), UdsError> {
-        if payload_length < Self::transfer_req_calculate_length(0, 0) {
+        if payload_length < transfer_req_calculate_length(0, 0) {
             return Err(PayloadLengthTooShort {
                 value: payload_length as u32,
-                expected: Self::transfer_req_calculate_length(0, 0) as u32,
+                expected: transfer_req_calculate_length(0, 0) as u32,
             });
         }
-        let compress_encrypt = reader.read_u8()?;
-        self.encryption_method = compress_encrypt & 0x0f;
-        self.compression_method = compress_encrypt >> 4;
-
-        let memory_bytes = reader.read_u8()?;
-        self.memory_address_bytes = memory_bytes & 0x0f;
-        self.memory_size_bytes = memory_bytes >> 4;
-
-        self.memory_address = read_sized(reader, self.memory_address_bytes.into())?;
-        self.memory_size = read_sized(reader, self.memory_size_bytes.into())?;
+        (self.compression_method, self.encryption_method) = read_data_format(reader)?;
+        (
+            self.memory_address_bytes,
+            self.memory_size_bytes,
+            self.memory_address,
+            self.memory_size,
+        ) = read_address_and_size(reader)?;
         Ok(())
     }
 
     fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
-        let compress_encrypt: u8 = (self.compression_method << 4) | (self.encryption_method & 0x0f);
-        writer.write_u8(compress_encrypt)?;
-        let memory_bytes: u8 = (self.memory_size_bytes << 4) | (self.memory_address_bytes & 0x0f);
-        writer.write_u8(memory_bytes)?;
-
-        write_sized(
+        write_data_format(writer, self.compression_method, self.encryption_method)?;
+        write_address_and_size(
             writer,
+            self.memory_address_bytes,
+            self.memory_size_bytes,
             self.memory_address,
-            self.memory_address_bytes.into(),
-        )?;
-        write_sized(writer, self.memory_size, self.memory_size_bytes.into())?;
-        Ok(())
-    }
-}
-
-impl RequestDownloadRsp {
-    fn transfer_rsp_calculate_length(nb_bytes: u8) -> usize {
-        1 + nb_bytes as usize
+            self.memory_size,
+        )
     }
 }
 
-impl Payload for RequestDownloadRsp {
+impl Payload for TransferResponse {
     fn length(&self) -> usize {
-        Self::transfer_rsp_calculate_length(self.max_block_size_bytes)
+        transfer_rsp_calculate_length(self.max_block_size_bytes)
     }
 
     fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
@@ -115,32 +183,18 @@ impl Payload for RequestDownloadRsp {
         reader: &mut T,
         payload_length: usize,
     ) -> Result<(), UdsError> {
-        if payload_length < Self::transfer_rsp_calculate_length(0) {
+        if payload_length < transfer_rsp_calculate_length(0) {
             return Err(PayloadLengthTooShort {
                 value: payload_length as u32,
-                expected: Self::transfer_rsp_calculate_length(0) as u32,
+                expected: transfer_rsp_calculate_length(0) as u32,
             });
         }
-        self.max_block_size_bytes = reader.read_u8()?;
-        self.max_block_size_bytes >>= 4;
-        println!(
-            "RequestDownloadRsp: max_block_size_bytes={}",
-            self.max_block_size_bytes
-        );
-        self.max_block_size = read_sized(reader, self.max_block_size_bytes.into())?;
-        println!("RequestDownloadRsp: max_block_size={}", self.max_block_size);
+        (self.max_block_size_bytes, self.max_block_size) = read_max_block_size(reader)?;
         Ok(())
     }
 
     fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
-        let max_block_size_bytes: u8 = self.max_block_size_bytes << 4;
-        writer.write_u8(max_block_size_bytes)?;
-        write_sized(
-            writer,
-            self.max_block_size,
-            self.max_block_size_bytes.into(),
-        )?;
-        Ok(())
+        write_max_block_size(writer, self.max_block_size_bytes, self.max_block_size)
     }
 }
 
@@ -421,7 +475,7 @@ impl Payload for TransferDataReq {
 
 impl Payload for TransferDataRsp {
     fn length(&self) -> usize {
-        1
+        1 + self.data.len()
     }
 
     fn read<T: Read>(reader: &mut T, payload_length: usize) -> Result<Self, UdsError> {
@@ -433,18 +487,21 @@ impl Payload for TransferDataRsp {
         reader: &mut T,
         payload_length: usize,
     ) -> Result<(), UdsError> {
-        if payload_length != 1 {
+        if payload_length < 1 {
             return Err(PayloadLengthTooShort {
                 value: payload_length as u32,
                 expected: 1u32,
             });
         }
         self.block_sequence_counter = reader.read_u8()?;
+        self.data.resize(payload_length - 1, 0);
+        reader.read_exact(&mut self.data)?;
         Ok(())
     }
 
     fn write<T: Write>(&self, writer: &mut T) -> Result<(), UdsError> {
         writer.write_u8(self.block_sequence_counter)?;
+        writer.write_all(&self.data)?;
         Ok(())
     }
 }
@@ -3,7 +3,9 @@ use std::{
     io::{self},
 };
 
-use uds_rw::{uds_read, uds_write, UdsError, UdsMessage};
+use uds_rw::{
+    message::DidLengthSource, uds_read, uds_read_with_did_lengths, uds_write, UdsError, UdsMessage,
+};
 
 struct Buffer {
     cursor: usize,
@@ -48,6 +50,18 @@ pub fn test_encode_decode(msg: &UdsMessage, expected: &[u8]) {
     assert_eq!(&req_back, msg);
 }
 
+pub fn test_encode_decode_with_did_lengths<S: DidLengthSource>(
+    msg: &UdsMessage,
+    expected: &[u8],
+    lengths: &S,
+) {
+    let mut ser = vec![];
+    uds_write(&mut ser, msg).unwrap();
+    assert_eq!(expected, &ser);
+    let req_back = uds_read_with_did_lengths(&mut Buffer::new(&ser), ser.len(), lengths).unwrap();
+    assert_eq!(&req_back, msg);
+}
+
 pub fn test_decode_serialized_truncated(truncated: &[u8]) {
     let req_back = uds_read(&mut Buffer::new(truncated), truncated.len());
     println!("RJK: {req_back:?}");
@@ -0,0 +1,115 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use uds_rw::UdsMessage;
+
+#[test]
+fn communication_control_req_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::CommunicationControlReq(CommunicationControlReq {
+        control_type: CommunicationControlType::DisableRxAndTx,
+        communication_type: CommunicationType {
+            normal_messages: true,
+            network_management_messages: true,
+            subnet: CommunicationSubnet::AllNetworks,
+            reserved: 0,
+        },
+        node_identification_number: None,
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x28, 0x83, 0x03];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn communication_control_req_subnet_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::CommunicationControlReq(CommunicationControlReq {
+        control_type: CommunicationControlType::EnableRxAndDisableTx,
+        communication_type: CommunicationType {
+            normal_messages: true,
+            network_management_messages: false,
+            subnet: CommunicationSubnet::Network(2),
+            reserved: 0,
+        },
+        node_identification_number: None,
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x28, 0x01, 0x21];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn communication_control_req_invalid_subnet() {
+    use uds_rw::message::*;
+    for n in [0x0, 0xf, 0x12] {
+        let req = UdsMessage::CommunicationControlReq(CommunicationControlReq {
+            communication_type: CommunicationType {
+                subnet: CommunicationSubnet::Network(n),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert!(uds_rw::uds_write(&mut vec![], &req).is_err());
+    }
+}
+
+#[test]
+fn communication_control_req_reserved_bits_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::CommunicationControlReq(CommunicationControlReq {
+        control_type: CommunicationControlType::EnableRxAndDisableTx,
+        communication_type: CommunicationType {
+            normal_messages: true,
+            network_management_messages: false,
+            subnet: CommunicationSubnet::Network(2),
+            reserved: 0x3,
+        },
+        node_identification_number: None,
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x28, 0x01, 0x2d];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn communication_control_req_invalid_reserved_bits() {
+    use uds_rw::message::*;
+    let req = UdsMessage::CommunicationControlReq(CommunicationControlReq {
+        communication_type: CommunicationType {
+            reserved: 0x4,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert!(uds_rw::uds_write(&mut vec![], &req).is_err());
+}
+
+#[test]
+fn communication_control_req_enhanced_address_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::CommunicationControlReq(CommunicationControlReq {
+        control_type: CommunicationControlType::EnableRxAndDisableTxWithEnhancedAddressInformation,
+        communication_type: CommunicationType {
+            normal_messages: false,
+            network_management_messages: true,
+            subnet: CommunicationSubnet::ReceivingNetwork,
+            reserved: 0,
+        },
+        node_identification_number: Some(0x0a12),
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x28, 0x04, 0xf2, 0x0a, 0x12];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn communication_control_rsp_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::CommunicationControlRsp(CommunicationControlRsp {
+        control_type: CommunicationControlType::DisableRxAndTx,
+    });
+    let exp = vec![0x68, 0x03];
+    test_encode_decode(&req, &exp);
+}
@@ -0,0 +1,142 @@
+use uds_rw::message::ReadDIDRsp;
+use uds_rw::{DidDictionary, DidField, DidFieldType, DidLayout, DidValue, DID_VIN};
+
+fn dictionary() -> DidDictionary {
+    let mut dictionary = DidDictionary::iso14229();
+    dictionary.insert(
+        0x0102,
+        DidLayout::new(
+            "Temperatures",
+            vec![
+                DidField::new(
+                    "coolant",
+                    1,
+                    DidFieldType::Signed {
+                        scale: 1.0,
+                        offset: 0.0,
+                        unit: Some("°C".to_string()),
+                    },
+                ),
+                DidField::new(
+                    "oil",
+                    2,
+                    DidFieldType::Unsigned {
+                        scale: 0.1,
+                        offset: -40.0,
+                        unit: Some("°C".to_string()),
+                    },
+                ),
+                DidField::new("raw", 2, DidFieldType::Bytes),
+            ],
+        ),
+    );
+    dictionary
+}
+
+#[test]
+fn did_dictionary_decode_encode() {
+    let dictionary = dictionary();
+    let data = vec![0xf6, 0x04, 0xb0, 0xca, 0xfe];
+    let values = dictionary.decode(0x0102, &data).unwrap();
+    let celsius = |value| DidValue::Number {
+        value,
+        unit: Some("°C".to_string()),
+    };
+    assert_eq!(values[0], ("coolant".to_string(), celsius(-10.0)));
+    assert_eq!(values[1].1.to_string(), "80 °C");
+    assert_eq!(
+        values[2],
+        ("raw".to_string(), DidValue::Bytes(vec![0xca, 0xfe]))
+    );
+
+    let mut reordered = values.clone();
+    reordered.reverse();
+    assert_eq!(dictionary.encode(0x0102, &reordered).unwrap(), data);
+}
+
+#[test]
+fn did_dictionary_read_did_rsp() {
+    let dictionary = dictionary();
+    let rsp = ReadDIDRsp::from_records(&[
+        (DID_VIN, b"WVWZZZ1JZXW000001".to_vec()),
+        (0xf186, vec![0x03]),
+    ]);
+    let records = rsp.records(&dictionary).unwrap();
+    assert_eq!(
+        dictionary.decode(records[0].0, &records[0].1).unwrap(),
+        vec![(
+            "vin".to_string(),
+            DidValue::Text("WVWZZZ1JZXW000001".to_string())
+        )]
+    );
+    let session = dictionary.decode(records[1].0, &records[1].1).unwrap();
+    assert_eq!(session[0].1.to_string(), "ExtendedDiagnosticSession (0x03)");
+}
+
+#[test]
+fn did_dictionary_errors() {
+    let dictionary = dictionary();
+    assert!(dictionary.decode(0x0103, &[0x00]).is_err());
+    assert!(dictionary.decode(0x0102, &[0x00, 0x01]).is_err());
+    assert!(dictionary
+        .encode(
+            DID_VIN,
+            &[("vin".to_string(), DidValue::Text("WVW".to_string()))]
+        )
+        .is_err());
+    let too_hot = DidValue::Number {
+        value: 200.0,
+        unit: None,
+    };
+    let values = vec![
+        ("coolant".to_string(), too_hot),
+        ("oil".to_string(), DidValue::Bytes(vec![])),
+    ];
+    assert!(dictionary.encode(0x0102, &values).is_err());
+}
+
+#[test]
+fn did_dictionary_signed_field_too_long() {
+    let mut dictionary = DidDictionary::new();
+    dictionary.insert(
+        0x0104,
+        DidLayout::new(
+            "Wide",
+            vec![DidField::new(
+                "value",
+                9,
+                DidFieldType::Signed {
+                    scale: 1.0,
+                    offset: 0.0,
+                    unit: None,
+                },
+            )],
+        ),
+    );
+    assert!(dictionary.decode(0x0104, &[0xff; 9]).is_err());
+}
+
+#[test]
+fn did_dictionary_encode_not_finite_err() {
+    let dictionary = dictionary();
+    for coolant in [f64::NAN, f64::INFINITY] {
+        let values = vec![
+            (
+                "coolant".to_string(),
+                DidValue::Number {
+                    value: coolant,
+                    unit: None,
+                },
+            ),
+            (
+                "oil".to_string(),
+                DidValue::Number {
+                    value: 80.0,
+                    unit: None,
+                },
+            ),
+            ("raw".to_string(), DidValue::Bytes(vec![0xca, 0xfe])),
+        ];
+        assert!(dictionary.encode(0x0102, &values).is_err());
+    }
+}
@@ -7,22 +7,82 @@ use uds_rw::UdsMessage;
 #[test]
 fn read_did_req_ok() {
     use uds_rw::message::ReadDIDReq;
-    let req = UdsMessage::ReadDIDReq(ReadDIDReq { did: 0xf180 });
+    let req = UdsMessage::ReadDIDReq(ReadDIDReq { dids: vec![0xf180] });
     let exp = vec![0x22, 0xf1, 0x80];
     test_encode_decode(&req, &exp);
 }
 
+#[test]
+fn read_did_req_multiple_ok() {
+    use uds_rw::message::ReadDIDReq;
+    let req = UdsMessage::ReadDIDReq(ReadDIDReq {
+        dids: vec![0xf180, 0xf190, 0x0102],
+    });
+    let exp = vec![0x22, 0xf1, 0x80, 0xf1, 0x90, 0x01, 0x02];
+    test_encode_decode(&req, &exp);
+}
+
 #[test]
 fn read_did_rsp_ok() {
     use uds_rw::message::ReadDIDRsp;
     let req = UdsMessage::ReadDIDRsp(ReadDIDRsp {
         did: 0xf180,
         user_data: vec![0x10, 0x14],
+        other_records: vec![],
     });
     let exp = vec![0x62, 0xf1, 0x80, 0x10, 0x14];
     test_encode_decode(&req, &exp);
 }
 
+#[test]
+fn read_did_rsp_records_ok() {
+    use std::collections::HashMap;
+    use uds_rw::message::ReadDIDRsp;
+    let records = vec![(0xf180, vec![0x10, 0x14]), (0xf190, vec![0x30, 0x39, 0x41])];
+    let rsp = ReadDIDRsp::from_records(&records);
+    let exp = vec![0x62, 0xf1, 0x80, 0x10, 0x14, 0xf1, 0x90, 0x30, 0x39, 0x41];
+    test_encode_decode(&UdsMessage::ReadDIDRsp(rsp.clone()), &exp);
+
+    let lengths = HashMap::from([(0xf180, 2), (0xf190, 3)]);
+    assert_eq!(rsp.records(&lengths).unwrap(), records);
+    // Without a known length, all remaining bytes belong to the first DID
+    assert_eq!(
+        rsp.records(&HashMap::new()).unwrap(),
+        vec![(0xf180, exp[3..].to_vec())]
+    );
+}
+
+#[test]
+fn read_did_rsp_with_did_lengths_ok() {
+    use common::test_encode_decode_with_did_lengths;
+    use std::collections::HashMap;
+    use uds_rw::message::ReadDIDRsp;
+    let rsp = UdsMessage::ReadDIDRsp(ReadDIDRsp {
+        did: 0xf180,
+        user_data: vec![0x10, 0x14],
+        other_records: vec![(0xf190, vec![0x30, 0x39, 0x41])],
+    });
+    let exp = vec![0x62, 0xf1, 0x80, 0x10, 0x14, 0xf1, 0x90, 0x30, 0x39, 0x41];
+    let lengths = HashMap::from([(0xf180, 2), (0xf190, 3)]);
+    test_encode_decode_with_did_lengths(&rsp, &exp, &lengths);
+}
+
+#[test]
+fn read_did_rsp_records_truncated() {
+    use uds_rw::message::ReadDIDRsp;
+    let rsp = ReadDIDRsp {
+        did: 0xf180,
+        user_data: vec![0x10, 0x14, 0xf1, 0x90, 0x30],
+        other_records: vec![],
+    };
+    let lengths = |did| match did {
+        0xf180 => Some(2),
+        0xf190 => Some(3),
+        _ => None,
+    };
+    assert!(rsp.records(&lengths).is_err());
+}
+
 #[test]
 fn write_did_req_ok() {
     use uds_rw::message::WriteDIDReq;
@@ -41,3 +101,181 @@ fn write_did_rsp_ok() {
     let exp = vec![0x6e, 0xf1, 0x80];
     test_encode_decode(&req, &exp);
 }
+
+#[test]
+fn dynamically_define_did_by_identifier_req_ok() {
+    use uds_rw::message::{DidSourceElement, DynamicDidDefinition, DynamicallyDefineDIDReq};
+    let req = UdsMessage::DynamicallyDefineDIDReq(DynamicallyDefineDIDReq {
+        definition: DynamicDidDefinition::DefineByIdentifier {
+            did: 0xf301,
+            sources: vec![
+                DidSourceElement {
+                    source_did: 0x1234,
+                    position: 1,
+                    memory_size: 2,
+                },
+                DidSourceElement {
+                    source_did: 0x5678,
+                    position: 3,
+                    memory_size: 1,
+                },
+            ],
+        },
+        suppress_positive_response: false,
+    });
+    let exp = vec![
+        0x2c, 0x01, 0xf3, 0x01, 0x12, 0x34, 0x01, 0x02, 0x56, 0x78, 0x03, 0x01,
+    ];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn dynamically_define_did_by_memory_address_req_ok() {
+    use uds_rw::message::{DynamicDidDefinition, DynamicallyDefineDIDReq, MemorySourceElement};
+    let req = UdsMessage::DynamicallyDefineDIDReq(DynamicallyDefineDIDReq {
+        definition: DynamicDidDefinition::DefineByMemoryAddress {
+            did: 0xf302,
+            memory_size_bytes: 1,
+            memory_address_bytes: 4,
+            sources: vec![
+                MemorySourceElement {
+                    memory_address: 0x2100_0000,
+                    memory_size: 0x10,
+                },
+                MemorySourceElement {
+                    memory_address: 0x2100_0100,
+                    memory_size: 0x04,
+                },
+            ],
+        },
+        suppress_positive_response: true,
+    });
+    let exp = vec![
+        0x2c, 0x82, 0xf3, 0x02, 0x14, 0x21, 0x00, 0x00, 0x00, 0x10, 0x21, 0x00, 0x01, 0x00, 0x04,
+    ];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn dynamically_define_did_clear_req_ok() {
+    use uds_rw::message::{DynamicDidDefinition, DynamicallyDefineDIDReq};
+    let req = UdsMessage::DynamicallyDefineDIDReq(DynamicallyDefineDIDReq {
+        definition: DynamicDidDefinition::ClearDynamicallyDefinedDataIdentifier {
+            did: Some(0xf301),
+        },
+        suppress_positive_response: false,
+    });
+    test_encode_decode(&req, &[0x2c, 0x03, 0xf3, 0x01]);
+
+    let req = UdsMessage::DynamicallyDefineDIDReq(DynamicallyDefineDIDReq::default());
+    test_encode_decode(&req, &[0x2c, 0x03]);
+}
+
+#[test]
+fn dynamically_define_did_req_invalid() {
+    let invalid: [&[u8]; 4] = [
+        &[0x2c, 0x01, 0xf3, 0x01, 0x12, 0x34, 0x01],
+        &[0x2c, 0x02, 0xf3, 0x02, 0x14],
+        &[0x2c, 0x02, 0xf3, 0x02, 0x14, 0x21, 0x00, 0x00],
+        &[0x2c, 0x04, 0xf3, 0x01],
+    ];
+    for data in invalid {
+        assert!(uds_rw::uds_read(&mut &data[..], data.len()).is_err());
+    }
+}
+
+#[test]
+fn dynamically_define_did_by_memory_address_req_too_many_bytes_err() {
+    use uds_rw::message::{DynamicDidDefinition, DynamicallyDefineDIDReq, MemorySourceElement};
+    let req = UdsMessage::DynamicallyDefineDIDReq(DynamicallyDefineDIDReq {
+        definition: DynamicDidDefinition::DefineByMemoryAddress {
+            did: 0xf302,
+            memory_size_bytes: 16,
+            memory_address_bytes: 4,
+            sources: vec![MemorySourceElement {
+                memory_address: 0x2100_0000,
+                memory_size: 0x10,
+            }],
+        },
+        suppress_positive_response: false,
+    });
+    assert!(uds_rw::uds_write(&mut vec![], &req).is_err());
+}
+
+#[test]
+fn dynamically_define_did_rsp_ok() {
+    use uds_rw::message::{DynamicDidDefinitionType, DynamicallyDefineDIDRsp};
+    let rsp = UdsMessage::DynamicallyDefineDIDRsp(DynamicallyDefineDIDRsp {
+        definition_type: DynamicDidDefinitionType::DefineByIdentifier,
+        did: Some(0xf301),
+    });
+    test_encode_decode(&rsp, &[0x6c, 0x01, 0xf3, 0x01]);
+
+    let rsp = UdsMessage::DynamicallyDefineDIDRsp(DynamicallyDefineDIDRsp {
+        definition_type: DynamicDidDefinitionType::ClearDynamicallyDefinedDataIdentifier,
+        did: None,
+    });
+    test_encode_decode(&rsp, &[0x6c, 0x03]);
+}
+
+#[test]
+fn read_periodic_did_req_ok() {
+    use uds_rw::message::{ReadPeriodicDIDReq, TransmissionMode};
+    let req = UdsMessage::ReadPeriodicDIDReq(ReadPeriodicDIDReq {
+        transmission_mode: TransmissionMode::SendAtFastRate,
+        periodic_ids: vec![0x01, 0x02],
+    });
+    test_encode_decode(&req, &[0x2a, 0x03, 0x01, 0x02]);
+
+    let req = UdsMessage::ReadPeriodicDIDReq(ReadPeriodicDIDReq {
+        transmission_mode: TransmissionMode::StopSending,
+        periodic_ids: vec![],
+    });
+    test_encode_decode(&req, &[0x2a, 0x04]);
+}
+
+#[test]
+fn read_periodic_did_req_without_id_err() {
+    use uds_rw::message::{ReadPeriodicDIDReq, TransmissionMode};
+    let req = UdsMessage::ReadPeriodicDIDReq(ReadPeriodicDIDReq {
+        transmission_mode: TransmissionMode::SendAtSlowRate,
+        periodic_ids: vec![],
+    });
+    assert!(uds_rw::uds_write(&mut vec![], &req).is_err());
+}
+
+#[test]
+fn read_periodic_did_rsp_ok() {
+    use uds_rw::message::ReadPeriodicDIDRsp;
+    let rsp = UdsMessage::ReadPeriodicDIDRsp(ReadPeriodicDIDRsp { frame: None });
+    test_encode_decode(&rsp, &[0x6a]);
+}
+
+#[test]
+fn read_periodic_did_rsp_type_1_frame_ok() {
+    use uds_rw::message::{PeriodicDataFrame, ReadPeriodicDIDRsp};
+    let rsp = UdsMessage::ReadPeriodicDIDRsp(ReadPeriodicDIDRsp {
+        frame: Some(PeriodicDataFrame {
+            periodic_id: 0x02,
+            data: vec![0x62, 0x10],
+        }),
+    });
+    test_encode_decode(&rsp, &[0x6a, 0x02, 0x62, 0x10]);
+}
+
+#[test]
+fn periodic_data_frame_ok() {
+    use uds_rw::message::PeriodicDataFrame;
+    use uds_rw::{uds_read_periodic, uds_write_periodic};
+    let frame = PeriodicDataFrame {
+        periodic_id: 0x02,
+        data: vec![0x62, 0x10],
+    };
+    let exp = vec![0x02, 0x62, 0x10];
+    let mut ser = vec![];
+    uds_write_periodic(&mut ser, &frame).unwrap();
+    assert_eq!(ser, exp);
+    assert_eq!(uds_read_periodic(&mut &exp[..], exp.len()).unwrap(), frame);
+    assert_eq!(frame.did(), 0xf202);
+    assert!(uds_read_periodic(&mut &exp[..0], 0).is_err());
+}
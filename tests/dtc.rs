@@ -9,7 +9,7 @@ mod request {
     fn dtc_report_number_of_dtc_by_status_mask() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportNumberOfDTCByStatusMask(ReportNumberOfDTCByStatusMask {
-                mask: 0xa0,
+                mask: 0xa0.into(),
             }),
         });
         let exp = vec![0x19, 0x01, 0xa0];
@@ -19,7 +19,9 @@ mod request {
     #[test]
     fn dtc_report_dtc_by_status_mask() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
-            sub: DTCReqSubfunction::ReportDTCByStatusMask(ReportDTCByStatusMask { mask: 0xa0 }),
+            sub: DTCReqSubfunction::ReportDTCByStatusMask(ReportDTCByStatusMask {
+                mask: 0xa0.into(),
+            }),
         });
         let exp = vec![0x19, 0x02, 0xa0];
         test_encode_decode(&req, &exp);
@@ -81,10 +83,13 @@ mod request {
     fn dtc_report_number_of_dtc_by_severity_mask_record() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportNumberOfDTCBySeverityMaskRecord(
-                ReportNumberOfDTCBySeverityMaskRecord { mask: 0xff80 },
+                ReportNumberOfDTCBySeverityMaskRecord {
+                    severity_mask: DtcSeverity::CHECK_IMMEDIATELY | DtcSeverity::DTC_CLASS_4,
+                    status_mask: DtcStatus::WARNING_INDICATOR_REQUESTED,
+                },
             ),
         });
-        let exp = vec![0x19, 0x07, 0xff, 0x80];
+        let exp = vec![0x19, 0x07, 0x90, 0x80];
         test_encode_decode(&req, &exp);
     }
 
@@ -92,7 +97,8 @@ mod request {
     fn dtc_report_dtc_by_severity_mask_record() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportDTCBySeverityMaskRecord(ReportDTCBySeverityMaskRecord {
-                mask: 0x1234,
+                severity_mask: 0x12.into(),
+                status_mask: 0x34.into(),
             }),
         });
         let exp = vec![0x19, 0x08, 0x12, 0x34];
@@ -161,7 +167,7 @@ mod request {
     fn dtc_report_mirror_memory_dtc_by_status_mask_dtc() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportMirrorMemoryDTCByStatusMask(
-                ReportMirrorMemoryDTCByStatusMask { mask: 0x4 },
+                ReportMirrorMemoryDTCByStatusMask { mask: 0x4.into() },
             ),
         });
         let exp = vec![0x19, 0x0f, 0x04];
@@ -185,7 +191,7 @@ mod request {
     fn dtc_report_number_of_mirror_memory_dtc_by_status_mask() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportNumberOfMirrorMemoryDTCByStatusMask(
-                ReportNumberOfMirrorMemoryDTCByStatusMask { mask: 0xf0 },
+                ReportNumberOfMirrorMemoryDTCByStatusMask { mask: 0xf0.into() },
             ),
         });
         let exp = vec![0x19, 0x11, 0xf0];
@@ -196,7 +202,7 @@ mod request {
     fn dtc_report_number_of_emissions_obddtc_by_status_mask() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportNumberOfEmissionsOBDDTCByStatusMask(
-                ReportNumberOfEmissionsOBDDTCByStatusMask { mask: 0xf0 },
+                ReportNumberOfEmissionsOBDDTCByStatusMask { mask: 0xf0.into() },
             ),
         });
         let exp = vec![0x19, 0x12, 0xf0];
@@ -207,7 +213,7 @@ mod request {
     fn dtc_report_emissions_obddtc_by_status_mask() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportEmissionsOBDDTCByStatusMask(
-                ReportEmissionsOBDDTCByStatusMask { mask: 0xf0 },
+                ReportEmissionsOBDDTCByStatusMask { mask: 0xf0.into() },
             ),
         });
         let exp = vec![0x19, 0x13, 0xf0];
@@ -247,7 +253,7 @@ mod request {
     fn dtc_report_dtc_user_def_memory_dtc_by_status_mask() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportUserDefMemoryDTCByStatusMask(
-                ReportUserDefMemoryDTCByStatusMask { mask: 0xf0 },
+                ReportUserDefMemoryDTCByStatusMask { mask: 0xf0.into() },
             ),
         });
         let exp = vec![0x19, 0x17, 0xf0];
@@ -288,10 +294,12 @@ mod request {
     fn dtc_report_wwobddtc_by_mask_record() {
         let req = UdsMessage::ReadDTCReq(ReadDTCReq {
             sub: DTCReqSubfunction::ReportWWHOBDDTCByMaskRecord(ReportWWHOBDDTCByMaskRecord {
-                group: 0x17,
+                group: 0x33,
+                status_mask: DtcStatus::CONFIRMED_DTC,
+                severity_mask: DtcSeverity::DTC_CLASS_1 | DtcSeverity::DTC_CLASS_2,
             }),
         });
-        let exp = vec![0x19, 0x42, 0x17];
+        let exp = vec![0x19, 0x42, 0x33, 0x08, 0x06];
         test_encode_decode(&req, &exp);
     }
 
@@ -305,19 +313,73 @@ mod request {
         let exp = vec![0x19, 0x55, 0x9];
         test_encode_decode(&req, &exp);
     }
+
+    #[test]
+    fn dtc_report_supported_dtc_ext_data_record() {
+        let req = UdsMessage::ReadDTCReq(ReadDTCReq {
+            sub: DTCReqSubfunction::ReportSupportedDTCExtDataRecord(
+                ReportSupportedDTCExtDataRecord { record: 0x91 },
+            ),
+        });
+        let exp = vec![0x19, 0x1a, 0x91];
+        test_encode_decode(&req, &exp);
+    }
+
+    #[test]
+    fn dtc_report_dtc_information_by_dtc_readiness_group_identifier() {
+        let req = UdsMessage::ReadDTCReq(ReadDTCReq {
+            sub: DTCReqSubfunction::ReportDTCInformationByDTCReadinessGroupIdentifier(
+                ReportDTCInformationByDTCReadinessGroupIdentifier {
+                    group: 0x33,
+                    readiness_group: 0x02,
+                },
+            ),
+        });
+        let exp = vec![0x19, 0x56, 0x33, 0x02];
+        test_encode_decode(&req, &exp);
+    }
 }
 
 mod response {
-    use super::common::test_encode_decode;
-    use uds_rw::{message::*, UdsMessage};
+    use super::common::{test_encode_decode, test_encode_decode_with_did_lengths};
+    use std::collections::HashMap;
+    use uds_rw::{message::*, uds_read_with_did_lengths, UdsMessage};
+
+    #[test]
+    fn dtc_status_flags() {
+        let mut status = DtcStatus::TEST_FAILED | DtcStatus::CONFIRMED_DTC;
+        assert_eq!(status.bits(), 0x09);
+        assert!(status.contains(DtcStatus::CONFIRMED_DTC));
+        assert!(!status.contains(DtcStatus::PENDING_DTC));
+        status.insert(DtcStatus::WARNING_INDICATOR_REQUESTED);
+        status.remove(DtcStatus::TEST_FAILED);
+        assert_eq!(status, DtcStatus::from_bits(0x88));
+        assert_eq!(
+            status.to_string(),
+            "[confirmedDTC, warningIndicatorRequested]"
+        );
+        assert_eq!(DtcStatus::default().to_string(), "[]");
+    }
+
+    #[test]
+    fn dtc_severity_flags() {
+        let severity = DtcSeverity::from(0x33);
+        assert!(severity.contains(DtcSeverity::MAINTENANCE_ONLY | DtcSeverity::DTC_CLASS_4));
+        assert!(!severity.contains(DtcSeverity::CHECK_IMMEDIATELY));
+        assert_eq!(
+            severity.to_string(),
+            "[DTCClass_0, DTCClass_1, DTCClass_4, maintenanceOnly]"
+        );
+        assert_eq!((severity & !DtcSeverity::from_bits(0x1f)).bits(), 0x20);
+    }
 
     #[test]
     fn dtc_response_number_of_dtc_by_status_mask() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseNumberOfDTCByStatusMask(
                 ResponseNumberOfDTCByStatusMask {
-                    mask: 0xff,
-                    format: 0xaa,
+                    mask: 0xff.into(),
+                    format: DtcFormat::Reserved(0xaa),
                     count: 2,
                 },
             ),
@@ -330,15 +392,15 @@ mod response {
     fn dtc_response_dtc_by_status_mask() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseDTCByStatusMask(ResponseDTCByStatusMask {
-                availability_mask: 0xf0,
+                availability_mask: 0xf0.into(),
                 dtcs: vec![
                     DtcAndStatusRecord {
                         dtc: 0x001011.into(),
-                        status: 0x03,
+                        status: 0x03.into(),
                     },
                     DtcAndStatusRecord {
                         dtc: 0x001012.into(),
-                        status: 0x04,
+                        status: 0x04.into(),
                     },
                 ],
             }),
@@ -362,7 +424,6 @@ mod response {
         test_encode_decode(&req, &exp);
     }
 
-    #[ignore] // Impossible to deserialize `vec` of unkonwn beforehand size
     #[test]
     fn dtc_response_dtc_snapshot_record_by_dtc_number() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
@@ -370,33 +431,81 @@ mod response {
                 ResponseDTCSnapshotRecordByDTCNumber {
                     dtc_status: DtcAndStatusRecord {
                         dtc: 0x001012.into(),
-                        status: 0x07,
+                        status: 0x07.into(),
                     },
-                    dtc_snapshot_records: DtcSnapshotRecords {
-                        dtc_snapshot_record_number: 2,
-                        dtc_snapshot_record_number_of_identifiers: 1,
-                        dtc_snapshot_record: vec![
-                            DidSnapshot {
+                    dtc_snapshot_records: vec![
+                        DtcSnapshotRecords {
+                            dtc_snapshot_record_number: 2,
+                            dtc_snapshot_record_number_of_identifiers: 2,
+                            dtc_snapshot_record: vec![
+                                DidSnapshot {
+                                    did: 0xf180,
+                                    value: vec![0x01, 0x02],
+                                },
+                                DidSnapshot {
+                                    did: 0xf181,
+                                    value: vec![0x03],
+                                },
+                            ],
+                        },
+                        DtcSnapshotRecords {
+                            dtc_snapshot_record_number: 3,
+                            dtc_snapshot_record_number_of_identifiers: 1,
+                            dtc_snapshot_record: vec![DidSnapshot {
                                 did: 0xf180,
-                                value: vec![0x01, 0x02],
-                            },
-                            DidSnapshot {
-                                did: 0xf181,
-                                value: vec![0x03, 0x03],
-                            },
-                        ],
-                    },
+                                value: vec![0x04, 0x05],
+                            }],
+                        },
+                    ],
                 },
             ),
         });
         let exp = vec![
-            0x59, 0x04, 0x00, 0x10, 0x12, 0x07, 2, 1, 0xf1, 0x80, 0x01, 0x02, 0xf1, 0x81, 0x03,
-            0x03,
+            0x59, 0x04, 0x00, 0x10, 0x12, 0x07, // Header
+            2, 2, 0xf1, 0x80, 0x01, 0x02, 0xf1, 0x81, 0x03, // First record
+            3, 1, 0xf1, 0x80, 0x04, 0x05,
         ];
-        test_encode_decode(&req, &exp);
+        let lengths = HashMap::from([(0xf180, 2), (0xf181, 1)]);
+        test_encode_decode_with_did_lengths(&req, &exp, &lengths);
+    }
+
+    #[test]
+    fn dtc_response_dtc_snapshot_record_unknown_did() {
+        let exp = vec![
+            0x59, 0x04, 0x00, 0x10, 0x12, 0x07, 2, 2, 0xf1, 0x80, 0x01, 0x02, 0xf1, 0x81, 0x03,
+        ];
+        let rsp = uds_read_with_did_lengths(&mut exp.as_slice(), exp.len(), &HashMap::new());
+        let Ok(UdsMessage::ReadDTCRsp(ReadDTCRsp {
+            sub: DTCRspSubfunction::ResponseDTCSnapshotRecordByDTCNumber(rsp),
+        })) = rsp
+        else {
+            panic!("unexpected {rsp:?}");
+        };
+        // Without DID lengths, the first DID takes the whole record
+        assert_eq!(
+            rsp.dtc_snapshot_records[0].dtc_snapshot_record,
+            vec![DidSnapshot {
+                did: 0xf180,
+                value: exp[10..].to_vec(),
+            }]
+        );
+    }
+
+    #[test]
+    fn dtc_response_dtc_snapshot_record_unknown_did_unsplit() {
+        let exp = vec![
+            0x59, 0x04, 0x00, 0x10, 0x12, 0x07, // Header
+            2, 2, 0xf1, 0x80, 0x01, 0x02, 0xf1, 0x81, 0x03, // First record
+            3, 1, 0xf1, 0x80, 0x04, 0x05,
+        ];
+        let lengths = HashMap::from([(0xf180, 2)]);
+        let rsp = uds_read_with_did_lengths(&mut exp.as_slice(), exp.len(), &lengths).unwrap();
+        assert_eq!(
+            rsp,
+            uds_rw::uds_read(&mut exp.as_slice(), exp.len()).unwrap()
+        );
     }
 
-    #[ignore] // Impossible to deserialize `vec` of unkonwn beforehand size
     #[test]
     fn dtc_response_dtc_store_data_by_record_number() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
@@ -407,7 +516,7 @@ mod response {
                             record_number: 4,
                             dtc_status: DtcAndStatusRecord {
                                 dtc: 0xfff000.into(),
-                                status: 0xf0,
+                                status: 0xf0.into(),
                             },
                             number_identifiers: 2,
                             data_records: vec![
@@ -425,7 +534,7 @@ mod response {
                             record_number: 5,
                             dtc_status: DtcAndStatusRecord {
                                 dtc: 0xfff000.into(),
-                                status: 0xf0,
+                                status: 0xf0.into(),
                             },
                             number_identifiers: 1,
                             data_records: vec![DidSnapshot {
@@ -443,7 +552,8 @@ mod response {
             0x34, // First Record
             5, 0xff, 0xf0, 0x00, 0xf0, 1, 0xf1, 0x80, 0x11, 0x32,
         ];
-        test_encode_decode(&req, &exp);
+        let lengths = |did| (did & 0xfff0 == 0xf180).then_some(2);
+        test_encode_decode_with_did_lengths(&req, &exp, &lengths);
     }
 
     #[ignore] // Impossible to deserialize `vec` of unkonwn beforehand size
@@ -454,7 +564,7 @@ mod response {
                 ResponseDTCExtDataRecordByDTCNumber {
                     dtc_status: DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0xa0,
+                        status: 0xa0.into(),
                     },
                     records: vec![
                         ExtDataRecordAndNumber {
@@ -481,8 +591,8 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseNumberOfDTCBySeverityMaskRecord(
                 ResponseNumberOfDTCBySeverityMaskRecord {
-                    mask: 0xa0,
-                    format: 0xaa,
+                    mask: 0xa0.into(),
+                    format: DtcFormat::Reserved(0xaa),
                     count: 3,
                 },
             ),
@@ -496,13 +606,13 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseDTCBySeverityMaskRecord(
                 ResponseDTCBySeverityMaskRecord {
-                    availability_mask: 0xf0,
+                    availability_mask: 0xf0.into(),
                     records: vec![DTCAndSeverityRecord {
-                        severity: 0x33,
+                        severity: 0x33.into(),
                         functional_unit: 1,
                         dtc_status: DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0xa0,
+                            status: 0xa0.into(),
                         },
                     }],
                 },
@@ -517,13 +627,13 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseSeverityInformationOfDTC(
                 ResponseDTCBySeverityMaskRecord {
-                    availability_mask: 0xf0,
+                    availability_mask: 0xf0.into(),
                     records: vec![DTCAndSeverityRecord {
-                        severity: 0x33,
+                        severity: 0x33.into(),
                         functional_unit: 1,
                         dtc_status: DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0xa0,
+                            status: 0xa0.into(),
                         },
                     }],
                 },
@@ -537,15 +647,15 @@ mod response {
     fn dtc_response_supported_dtc() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseSupportedDTC(ResponseSupportedDTC {
-                availability_mask: 0xa0,
+                availability_mask: 0xa0.into(),
                 dtcs: vec![
                     DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0xa0,
+                        status: 0xa0.into(),
                     },
                     DtcAndStatusRecord {
                         dtc: 0x00f181.into(),
-                        status: 0x80,
+                        status: 0x80.into(),
                     },
                 ],
             }),
@@ -560,15 +670,15 @@ mod response {
     fn dtc_response_first_test_failed_dtc() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseFirstTestFailedDTC(ResponseFirstTestFailedDTC {
-                availability_mask: 0x0a,
+                availability_mask: 0x0a.into(),
                 dtcs: vec![
                     DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0xa0,
+                        status: 0xa0.into(),
                     },
                     DtcAndStatusRecord {
                         dtc: 0x00f181.into(),
-                        status: 0x80,
+                        status: 0x80.into(),
                     },
                 ],
             }),
@@ -583,15 +693,15 @@ mod response {
     fn dtc_response_first_confirmed_dtc() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseFirstConfirmedDTC(ResponseFirstConfirmedDTC {
-                availability_mask: 0x0a,
+                availability_mask: 0x0a.into(),
                 dtcs: vec![
                     DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0xa0,
+                        status: 0xa0.into(),
                     },
                     DtcAndStatusRecord {
                         dtc: 0x00f181.into(),
-                        status: 0x80,
+                        status: 0x80.into(),
                     },
                 ],
             }),
@@ -607,15 +717,15 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseMostRecentTestFailedDTC(
                 ResponseMostRecentTestFailedDTC {
-                    availability_mask: 0x0a,
+                    availability_mask: 0x0a.into(),
                     dtcs: vec![
                         DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0xa0,
+                            status: 0xa0.into(),
                         },
                         DtcAndStatusRecord {
                             dtc: 0x00f181.into(),
-                            status: 0x80,
+                            status: 0x80.into(),
                         },
                     ],
                 },
@@ -632,15 +742,15 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseMostRecentConfirmedDTC(
                 ResponseMostRecentConfirmedDTC {
-                    availability_mask: 0x0a,
+                    availability_mask: 0x0a.into(),
                     dtcs: vec![
                         DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0xa0,
+                            status: 0xa0.into(),
                         },
                         DtcAndStatusRecord {
                             dtc: 0x00f181.into(),
-                            status: 0x80,
+                            status: 0x80.into(),
                         },
                     ],
                 },
@@ -657,15 +767,15 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseMirrorMemoryDTCByStatusMask(
                 ResponseMirrorMemoryDTCByStatusMask {
-                    availability_mask: 0x07,
+                    availability_mask: 0x07.into(),
                     dtcs: vec![
                         DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0x03,
+                            status: 0x03.into(),
                         },
                         DtcAndStatusRecord {
                             dtc: 0x00f181.into(),
-                            status: 0x04,
+                            status: 0x04.into(),
                         },
                     ],
                 },
@@ -685,7 +795,7 @@ mod response {
                 ResponseDTCExtDataRecordByDTCNumber {
                     dtc_status: DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0xa0,
+                        status: 0xa0.into(),
                     },
                     records: vec![
                         ExtDataRecordAndNumber {
@@ -711,8 +821,8 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseNumberOfMirrorMemoryDTCByStatusMask(
                 ResponseNumberOfMirrorMemoryDTCByStatusMask {
-                    mask: 0xa8,
-                    format: 0xb0,
+                    mask: 0xa8.into(),
+                    format: DtcFormat::Reserved(0xb0),
                     count: 2,
                 },
             ),
@@ -726,8 +836,8 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseNumberOfEmissionsOBDDTCByStatusMask(
                 ResponseNumberOfEmissionsOBDDTCByStatusMask {
-                    mask: 0xa8,
-                    format: 0xb0,
+                    mask: 0xa8.into(),
+                    format: DtcFormat::Reserved(0xb0),
                     count: 2,
                 },
             ),
@@ -741,15 +851,15 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseEmissionsOBDDTCByStatusMask(
                 ResponseEmissionsOBDDTCByStatusMask {
-                    availability_mask: 0x07,
+                    availability_mask: 0x07.into(),
                     dtcs: vec![
                         DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0x03,
+                            status: 0x03.into(),
                         },
                         DtcAndStatusRecord {
                             dtc: 0x00f181.into(),
-                            status: 0x04,
+                            status: 0x04.into(),
                         },
                     ],
                 },
@@ -788,15 +898,15 @@ mod response {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
             sub: DTCRspSubfunction::ResponseDTCWithPermanentStatus(
                 ResponseDTCWithPermanentStatus {
-                    availability_mask: 0x07,
+                    availability_mask: 0x07.into(),
                     dtcs: vec![
                         DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0x03,
+                            status: 0x03.into(),
                         },
                         DtcAndStatusRecord {
                             dtc: 0x00f181.into(),
-                            status: 0x04,
+                            status: 0x04.into(),
                         },
                     ],
                 },
@@ -816,20 +926,20 @@ mod response {
                 ResponseDTCExtDataRecordByRecordNumber {
                     dtc_status: DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0xa0,
+                        status: 0xa0.into(),
                     },
                     records: vec![
                         DtcAndStatusAndExtDataRecord {
                             dtc_status: DtcAndStatusRecord {
                                 dtc: 0x00f180.into(),
-                                status: 0xc0,
+                                status: 0xc0.into(),
                             },
                             extended_data: vec![0xa0, 0xb0],
                         },
                         DtcAndStatusAndExtDataRecord {
                             dtc_status: DtcAndStatusRecord {
                                 dtc: 0x00f181.into(),
-                                status: 0xc1,
+                                status: 0xc1.into(),
                             },
                             extended_data: vec![0xa1, 0xb1],
                         },
@@ -850,15 +960,15 @@ mod response {
             sub: DTCRspSubfunction::ResponseUserDefMemoryDTCByStatusMask(
                 ResponseUserDefMemoryDTCByStatusMask {
                     memory: 1,
-                    availability_mask: 0x03,
+                    availability_mask: 0x03.into(),
                     records: vec![
                         DtcAndStatusRecord {
                             dtc: 0x00f180.into(),
-                            status: 0x03,
+                            status: 0x03.into(),
                         },
                         DtcAndStatusRecord {
                             dtc: 0x00f181.into(),
-                            status: 0x04,
+                            status: 0x04.into(),
                         },
                     ],
                 },
@@ -870,7 +980,6 @@ mod response {
         test_encode_decode(&req, &exp);
     }
 
-    #[ignore] // Impossible to deserialize `vec` of unkonwn beforehand size
     #[test]
     fn dtc_response_user_def_memory_dtc_snapshot_record_by_dtc_number() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
@@ -879,31 +988,42 @@ mod response {
                     memory: 1,
                     dtc_status: DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0x03,
+                        status: 0x03.into(),
                     },
                     records: vec![
                         RecordNumberAndIdentifierAndSnapshot {
                             record_number: 1,
                             number_identifiers: 2,
-                            record: DidSnapshot {
-                                did: 0x1004,
-                                value: vec![0xaa, 0xcc],
-                            },
+                            records: vec![
+                                DidSnapshot {
+                                    did: 0x1004,
+                                    value: vec![0xaa, 0xcc],
+                                },
+                                DidSnapshot {
+                                    did: 0x1005,
+                                    value: vec![0x01],
+                                },
+                            ],
                         },
                         RecordNumberAndIdentifierAndSnapshot {
                             record_number: 3,
                             number_identifiers: 1,
-                            record: DidSnapshot {
+                            records: vec![DidSnapshot {
                                 did: 0x1004,
                                 value: vec![0xaa, 0xcc],
-                            },
+                            }],
                         },
                     ],
                 },
             ),
         });
-        let exp = vec![0x59, 0x18, 1, 0xf0, 0x00, 0x00, 0x03, 0x07];
-        test_encode_decode(&req, &exp);
+        let exp = vec![
+            0x59, 0x18, 1, 0x00, 0xf1, 0x80, 0x03, // Header
+            1, 2, 0x10, 0x04, 0xaa, 0xcc, 0x10, 0x05, 0x01, // First record
+            3, 1, 0x10, 0x04, 0xaa, 0xcc,
+        ];
+        let lengths = HashMap::from([(0x1004, 2), (0x1005, 1)]);
+        test_encode_decode_with_did_lengths(&req, &exp, &lengths);
     }
 
     #[ignore] // Impossible to deserialize `vec` of unkonwn beforehand size
@@ -915,7 +1035,7 @@ mod response {
                     memory: 1,
                     dtc_status: DtcAndStatusRecord {
                         dtc: 0x00f180.into(),
-                        status: 0x03,
+                        status: 0x03.into(),
                     },
                     records: vec![
                         ExtDataRecordAndNumber {
@@ -939,18 +1059,245 @@ mod response {
     #[test]
     fn dtc_response_wwobddtc_by_mask_record() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
-            sub: DTCRspSubfunction::ResponseWWHOBDDTCByMaskRecord(vec![0x41, 0x42, 0x43]),
+            sub: DTCRspSubfunction::ResponseWWHOBDDTCByMaskRecord(ResponseWWHOBDDTCByMaskRecord {
+                group: 0x33,
+                status_availability_mask: 0xff.into(),
+                severity_availability_mask: 0xe0.into(),
+                format: DtcFormat::SaeJ2012WwhObd,
+                records: vec![
+                    WWHOBDDTCAndSeverityRecord {
+                        severity: DtcSeverity::DTC_CLASS_2,
+                        dtc_status: DtcAndStatusRecord {
+                            dtc: Dtc::new(0x0a, 0x1b, 0x1c),
+                            status: 0x08.into(),
+                        },
+                    },
+                    WWHOBDDTCAndSeverityRecord {
+                        severity: DtcSeverity::CHECK_IMMEDIATELY,
+                        dtc_status: DtcAndStatusRecord {
+                            dtc: Dtc::new(0xc1, 0x00, 0x00),
+                            status: 0x09.into(),
+                        },
+                    },
+                ],
+            }),
         });
-        let exp = vec![0x59, 0x42, 0x41, 0x42, 0x43];
+        let exp = vec![
+            0x59, 0x42, 0x33, 0xff, 0xe0, 0x04, 0x04, 0x0a, 0x1b, 0x1c, 0x08, 0x80, 0xc1, 0x00,
+            0x00, 0x09,
+        ];
         test_encode_decode(&req, &exp);
     }
 
     #[test]
     fn dtc_response_wwobddtc_with_permanent_status() {
         let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
-            sub: DTCRspSubfunction::ResponseWWHOBDDTCWithPermanentStatus(vec![0x41, 0x42, 0x43]),
+            sub: DTCRspSubfunction::ResponseWWHOBDDTCWithPermanentStatus(
+                ResponseWWHOBDDTCWithPermanentStatus {
+                    group: 0x33,
+                    availability_mask: 0xff.into(),
+                    format: DtcFormat::SaeJ2012WwhObd,
+                    records: vec![DtcAndStatusRecord {
+                        dtc: Dtc::new(0x0a, 0x1b, 0x1c),
+                        status: 0x08.into(),
+                    }],
+                },
+            ),
+        });
+        let exp = vec![0x59, 0x55, 0x33, 0xff, 0x04, 0x0a, 0x1b, 0x1c, 0x08];
+        test_encode_decode(&req, &exp);
+    }
+
+    #[test]
+    fn dtc_response_supported_dtc_ext_data_record() {
+        let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
+            sub: DTCRspSubfunction::ResponseSupportedDTCExtDataRecord(
+                ResponseSupportedDTCExtDataRecord {
+                    availability_mask: 0xff.into(),
+                    record: 0x91,
+                    dtcs: vec![
+                        DtcAndStatusRecord {
+                            dtc: 0x0a1b1c.into(),
+                            status: 0x08.into(),
+                        },
+                        DtcAndStatusRecord {
+                            dtc: 0xc10000.into(),
+                            status: 0x2f.into(),
+                        },
+                    ],
+                },
+            ),
         });
-        let exp = vec![0x59, 0x55, 0x41, 0x42, 0x43];
+        let exp = vec![
+            0x59, 0x1a, 0xff, 0x91, 0x0a, 0x1b, 0x1c, 0x08, 0xc1, 0x00, 0x00, 0x2f,
+        ];
         test_encode_decode(&req, &exp);
     }
+
+    #[test]
+    fn dtc_response_dtc_information_by_dtc_readiness_group_identifier() {
+        let req = UdsMessage::ReadDTCRsp(ReadDTCRsp {
+            sub: DTCRspSubfunction::ResponseDTCInformationByDTCReadinessGroupIdentifier(
+                ResponseDTCInformationByDTCReadinessGroupIdentifier {
+                    group: 0x33,
+                    availability_mask: 0xff.into(),
+                    format: DtcFormat::SaeJ2012WwhObd,
+                    readiness_group: 0x02,
+                    dtcs: vec![DtcAndStatusRecord {
+                        dtc: 0x0a1b1c.into(),
+                        status: 0x08.into(),
+                    }],
+                },
+            ),
+        });
+        let exp = vec![0x59, 0x56, 0x33, 0xff, 0x04, 0x02, 0x0a, 0x1b, 0x1c, 0x08];
+        test_encode_decode(&req, &exp);
+    }
+}
+
+mod clear {
+    use super::common::test_encode_decode;
+    use uds_rw::{message::*, UdsMessage};
+
+    #[test]
+    fn clear_dtc_req_all_groups() {
+        let req = UdsMessage::ClearDTCReq(ClearDTCReq {
+            group: Dtc::ALL_GROUPS,
+            memory: None,
+        });
+        let exp = vec![0x14, 0xff, 0xff, 0xff];
+        test_encode_decode(&req, &exp);
+    }
+
+    #[test]
+    fn clear_dtc_req_memory_selection() {
+        let req = UdsMessage::ClearDTCReq(ClearDTCReq {
+            group: 0x00f180.into(),
+            memory: Some(0x02),
+        });
+        let exp = vec![0x14, 0x00, 0xf1, 0x80, 0x02];
+        test_encode_decode(&req, &exp);
+    }
+
+    #[test]
+    fn clear_dtc_rsp() {
+        let req = UdsMessage::ClearDTCRsp(ClearDTCRsp {});
+        let exp = vec![0x54];
+        test_encode_decode(&req, &exp);
+    }
+}
+
+mod setting {
+    use super::common::test_encode_decode;
+    use uds_rw::{message::*, UdsMessage};
+
+    #[test]
+    fn control_dtc_setting_req_off() {
+        let req = UdsMessage::ControlDTCSettingReq(ControlDTCSettingReq {
+            setting_type: DTCSettingType::Off,
+            option: DtcSettingOption::None,
+            suppress_positive_response: true,
+        });
+        let exp = vec![0x85, 0x82];
+        test_encode_decode(&req, &exp);
+    }
+
+    #[test]
+    fn control_dtc_setting_req_option_record() {
+        let exp = vec![0x85, 0x01, 0x00, 0xf1, 0x80, 0xff, 0xff, 0xff];
+        let req = ControlDTCSettingReq {
+            setting_type: DTCSettingType::On,
+            option: DtcSettingOption::Raw(exp[2..].to_vec()),
+            suppress_positive_response: false,
+        };
+        test_encode_decode(&UdsMessage::ControlDTCSettingReq(req.clone()), &exp);
+
+        let mut dtcs = req.clone();
+        dtcs.split_dtcs().unwrap();
+        assert_eq!(
+            dtcs.option,
+            DtcSettingOption::Dtcs(vec![0x00f180.into(), Dtc::ALL_GROUPS])
+        );
+        let mut ser = vec![];
+        uds_rw::uds_write(&mut ser, &UdsMessage::ControlDTCSettingReq(dtcs)).unwrap();
+        assert_eq!(ser, exp);
+    }
+
+    #[test]
+    fn control_dtc_setting_req_raw_option_record() {
+        let mut req = ControlDTCSettingReq {
+            setting_type: DTCSettingType::VehicleManufacturerSpecific(0x42),
+            option: DtcSettingOption::Raw(vec![0x12, 0x34]),
+            suppress_positive_response: false,
+        };
+        let msg = UdsMessage::ControlDTCSettingReq(req.clone());
+        test_encode_decode(&msg, &[0x85, 0x42, 0x12, 0x34]);
+        assert_eq!(
+            msg.to_string(),
+            "ControlDTCSettingReq(setting=VehicleManufacturerSpecific(66), record=[12, 34], suppress_pos_rsp=false)"
+        );
+        assert!(req.split_dtcs().is_err());
+    }
+
+    #[test]
+    fn control_dtc_setting_rsp() {
+        let req = UdsMessage::ControlDTCSettingRsp(ControlDTCSettingRsp {
+            setting_type: DTCSettingType::VehicleManufacturerSpecific(0x42),
+        });
+        let exp = vec![0xc5, 0x42];
+        test_encode_decode(&req, &exp);
+    }
+}
+
+mod format {
+    use uds_rw::message::*;
+
+    #[test]
+    fn dtc_j2012_conversion() {
+        let dtc = Dtc::new(0x0a, 0x1b, 0x1c);
+        assert_eq!(dtc.to_j2012(), "P0A1B-1C");
+        assert_eq!(Dtc::from_j2012("P0A1B-1C").unwrap(), dtc);
+        assert_eq!(
+            "u3fff-ff".parse::<Dtc>().unwrap(),
+            Dtc::new(0xff, 0xff, 0xff)
+        );
+        assert_eq!("C0123".parse::<Dtc>().unwrap(), Dtc::new(0x41, 0x23, 0x00));
+        assert_eq!("0x0a1b1c".parse::<Dtc>().unwrap(), dtc);
+        for invalid in ["X0A1B-1C", "P4A1B-1C", "P0A1-1C", "P0A1B-1", "P+A1B"] {
+            assert!(invalid.parse::<Dtc>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn dtc_display_by_format() {
+        let count = GotDTCCount {
+            mask: 0xff.into(),
+            format: DtcFormat::Iso15031,
+            count: 1,
+        };
+        let dtc = Dtc::new(0xc1, 0x00, 0x00);
+        assert_eq!(dtc.display(count.format).to_string(), "U0100-00");
+        assert_eq!(dtc.display(DtcFormat::Iso14229).to_string(), "0xc10000");
+        assert_eq!(DtcFormat::from(0x04), DtcFormat::SaeJ2012WwhObd);
+        assert_eq!(u8::from(DtcFormat::Reserved(0x12)), 0x12);
+    }
+
+    #[test]
+    fn dtc_j1939_spn_fmi() {
+        let dtc = Dtc::from_j1939(520192, 31).unwrap();
+        assert_eq!(dtc, Dtc::new(0x00, 0xf0, 0xff));
+        assert_eq!((dtc.spn(), dtc.fmi()), (520192, 31));
+        assert_eq!(
+            Dtc::new(0x6e, 0x00, 0x03)
+                .display(DtcFormat::SaeJ1939)
+                .to_string(),
+            "SPN 110 FMI 3"
+        );
+        assert_eq!(
+            dtc.display(DtcFormat::from(0x02)).to_string(),
+            "SPN 520192 FMI 31"
+        );
+        assert!(Dtc::from_j1939(0x80000, 0).is_err());
+        assert!(Dtc::from_j1939(0, 0x20).is_err());
+    }
 }
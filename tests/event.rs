@@ -0,0 +1,149 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use uds_rw::message::{
+    ComparisonLogic, DTCReqSubfunction, ReadDIDReq, ReadDTCReq, ResponseOnEventReq,
+    ResponseOnEventRsp, RoeEvent, ValueComparison, EVENT_WINDOW_INFINITE,
+};
+use uds_rw::{uds_read, uds_write, UdsMessage};
+
+fn report_most_recent_confirmed_dtc() -> Box<UdsMessage> {
+    Box::new(UdsMessage::ReadDTCReq(ReadDTCReq {
+        sub: DTCReqSubfunction::ReportMostRecentConfirmedDTC,
+    }))
+}
+
+#[test]
+fn response_on_event_on_dtc_status_change_req_ok() {
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::OnDTCStatusChange { mask: 0x08.into() },
+        store_event: false,
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        service_to_respond_to: Some(report_most_recent_confirmed_dtc()),
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x86, 0x01, 0x02, 0x08, 0x19, 0x0e];
+    test_encode_decode(&req, &exp);
+    assert_eq!(
+        req.to_string(),
+        "ResponseOnEventReq(OnDTCStatusChange(mask=[confirmedDTC]), window=0x02, \
+         service=ReadDTCReq::ReportMostRecentConfirmedDTC)"
+    );
+}
+
+#[test]
+fn response_on_event_on_change_of_did_req_ok() {
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::OnChangeOfDataIdentifier { did: 0x0190 },
+        store_event: true,
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        service_to_respond_to: Some(Box::new(UdsMessage::ReadDIDReq(ReadDIDReq {
+            dids: vec![0x0190],
+        }))),
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x86, 0xc3, 0x02, 0x01, 0x90, 0x22, 0x01, 0x90];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn response_on_event_on_comparison_of_values_req_ok() {
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::OnComparisonOfValues(ValueComparison {
+            did: 0x0190,
+            logic: ComparisonLogic::LargerThan,
+            reference: 0x0000_0bb8,
+            hysteresis: 10,
+            localization: 0x4008,
+        }),
+        store_event: false,
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        service_to_respond_to: Some(Box::new(UdsMessage::ReadDIDReq(ReadDIDReq {
+            dids: vec![0x0190],
+        }))),
+        suppress_positive_response: false,
+    });
+    let exp = vec![
+        0x86, 0x07, 0x02, 0x01, 0x90, 0x02, 0x00, 0x00, 0x0b, 0xb8, 0x0a, 0x40, 0x08, 0x22, 0x01,
+        0x90,
+    ];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn response_on_event_control_req_ok() {
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::StartResponseOnEvent,
+        store_event: false,
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        service_to_respond_to: None,
+        suppress_positive_response: false,
+    });
+    test_encode_decode(&req, &[0x86, 0x05, 0x02]);
+
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::ClearResponseOnEvent,
+        ..Default::default()
+    });
+    test_encode_decode(&req, &[0x86, 0x06]);
+
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::ReportActivatedEvents,
+        ..Default::default()
+    });
+    test_encode_decode(&req, &[0x86, 0x04]);
+}
+
+#[test]
+fn response_on_event_req_invalid() {
+    let req = UdsMessage::ResponseOnEventReq(ResponseOnEventReq {
+        event: RoeEvent::OnDTCStatusChange { mask: 0x08.into() },
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        ..Default::default()
+    });
+    assert!(uds_write(&mut vec![], &req).is_err());
+
+    let invalid: [&[u8]; 3] = [
+        &[0x86, 0x01, 0x02, 0x08],
+        &[0x86, 0x03, 0x02, 0x01],
+        &[0x86, 0x05, 0x02, 0x19, 0x0e],
+    ];
+    for data in invalid {
+        assert!(uds_read(&mut &data[..], data.len()).is_err());
+    }
+}
+
+#[test]
+fn response_on_event_rsp_ok() {
+    let rsp = UdsMessage::ResponseOnEventRsp(ResponseOnEventRsp {
+        event: RoeEvent::OnDTCStatusChange { mask: 0x08.into() },
+        store_event: false,
+        number_of_events: 0,
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        service_to_respond_to: Some(report_most_recent_confirmed_dtc()),
+        activated_events: vec![],
+    });
+    let exp = vec![0xc6, 0x01, 0x00, 0x02, 0x08, 0x19, 0x0e];
+    test_encode_decode(&rsp, &exp);
+
+    let rsp = UdsMessage::ResponseOnEventRsp(ResponseOnEventRsp {
+        event: RoeEvent::StartResponseOnEvent,
+        number_of_events: 1,
+        event_window_time: Some(EVENT_WINDOW_INFINITE),
+        ..Default::default()
+    });
+    test_encode_decode(&rsp, &[0xc6, 0x05, 0x01, 0x02]);
+}
+
+#[test]
+fn response_on_event_report_activated_events_rsp_ok() {
+    let rsp = UdsMessage::ResponseOnEventRsp(ResponseOnEventRsp {
+        event: RoeEvent::ReportActivatedEvents,
+        number_of_events: 1,
+        activated_events: vec![0x01, 0x02, 0x08, 0x19, 0x0e],
+        ..Default::default()
+    });
+    let exp = vec![0xc6, 0x04, 0x01, 0x01, 0x02, 0x08, 0x19, 0x0e];
+    test_encode_decode(&rsp, &exp);
+}
@@ -0,0 +1,89 @@
+#[allow(dead_code)]
+mod common;
+
+use common::{test_encode_decode, test_encode_decode_with_did_lengths};
+use std::collections::HashMap;
+use uds_rw::message::{IoControlParameter, IoControlReq, IoControlRsp};
+use uds_rw::{uds_write, UdsMessage};
+
+#[test]
+fn io_control_req_return_control_ok() {
+    let req = UdsMessage::IoControlReq(IoControlReq {
+        did: 0x4101,
+        parameter: IoControlParameter::ReturnControlToEcu,
+        control_state: vec![],
+        control_enable_mask: None,
+    });
+    let exp = vec![0x2f, 0x41, 0x01, 0x00];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn io_control_req_freeze_with_mask_ok() {
+    let req = UdsMessage::IoControlReq(IoControlReq {
+        did: 0x4101,
+        parameter: IoControlParameter::FreezeCurrentState,
+        control_state: vec![],
+        control_enable_mask: Some(vec![0x80]),
+    });
+    let exp = vec![0x2f, 0x41, 0x01, 0x02, 0x80];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn io_control_req_short_term_adjustment_ok() {
+    let req = UdsMessage::IoControlReq(IoControlReq {
+        did: 0x9b00,
+        parameter: IoControlParameter::ShortTermAdjustment,
+        control_state: vec![0x3c, 0x01],
+        control_enable_mask: None,
+    });
+    let exp = vec![0x2f, 0x9b, 0x00, 0x03, 0x3c, 0x01];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn io_control_req_short_term_adjustment_with_mask_ok() {
+    let req = UdsMessage::IoControlReq(IoControlReq {
+        did: 0x9b00,
+        parameter: IoControlParameter::ShortTermAdjustment,
+        control_state: vec![0x3c, 0x01],
+        control_enable_mask: Some(vec![0x40]),
+    });
+    let exp = vec![0x2f, 0x9b, 0x00, 0x03, 0x3c, 0x01, 0x40];
+    let lengths = HashMap::from([(0x9b00, 2)]);
+    test_encode_decode_with_did_lengths(&req, &exp, &lengths);
+}
+
+#[test]
+fn io_control_req_state_without_adjustment_err() {
+    let req = UdsMessage::IoControlReq(IoControlReq {
+        did: 0x9b00,
+        parameter: IoControlParameter::ResetToDefault,
+        control_state: vec![0x3c],
+        control_enable_mask: None,
+    });
+    assert!(uds_write(&mut vec![], &req).is_err());
+}
+
+#[test]
+fn io_control_rsp_ok() {
+    let rsp = UdsMessage::IoControlRsp(IoControlRsp {
+        did: 0x9b00,
+        parameter: IoControlParameter::ShortTermAdjustment,
+        control_state: vec![0x3c, 0x01],
+    });
+    let exp = vec![0x6f, 0x9b, 0x00, 0x03, 0x3c, 0x01];
+    test_encode_decode(&rsp, &exp);
+}
+
+#[test]
+fn io_control_rsp_reserved_parameter_ok() {
+    let rsp = UdsMessage::IoControlRsp(IoControlRsp {
+        did: 0x9b00,
+        parameter: IoControlParameter::Reserved(0x42),
+        control_state: vec![],
+    });
+    let exp = vec![0x6f, 0x9b, 0x00, 0x42];
+    test_encode_decode(&rsp, &exp);
+}
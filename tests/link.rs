@@ -0,0 +1,63 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use uds_rw::UdsMessage;
+
+#[test]
+fn link_control_req_fixed_baudrate_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::LinkControlReq(LinkControlReq::verify(LinkBaudrate::Can500000));
+    let exp = vec![0x87, 0x01, 0x12];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn link_control_req_specific_baudrate_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::LinkControlReq(LinkControlReq::verify(LinkBaudrate::Specific(2_000_000)));
+    let exp = vec![0x87, 0x02, 0x1e, 0x84, 0x80];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn link_control_req_transition_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::LinkControlReq(LinkControlReq {
+        suppress_positive_response: true,
+        ..LinkControlReq::transition()
+    });
+    let exp = vec![0x87, 0x83];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn link_control_req_invalid() {
+    use uds_rw::message::*;
+    for req in [
+        LinkControlReq {
+            control_type: LinkControlType::VerifyModeTransitionWithFixedParameter,
+            baudrate: Some(LinkBaudrate::Specific(500_000)),
+            suppress_positive_response: false,
+        },
+        LinkControlReq::verify(LinkBaudrate::Specific(0x0100_0000)),
+        LinkControlReq {
+            baudrate: Some(LinkBaudrate::Can125000),
+            ..LinkControlReq::transition()
+        },
+    ] {
+        assert!(uds_rw::uds_write(&mut vec![], &UdsMessage::LinkControlReq(req)).is_err());
+    }
+    let data = [0x87, 0x02, 0x1e, 0x84];
+    assert!(uds_rw::uds_read(&mut &data[..], data.len()).is_err());
+}
+
+#[test]
+fn link_control_rsp_ok() {
+    use uds_rw::message::*;
+    let rsp = UdsMessage::LinkControlRsp(LinkControlRsp {
+        control_type: LinkControlType::TransitionMode,
+    });
+    let exp = vec![0xc7, 0x03];
+    test_encode_decode(&rsp, &exp);
+}
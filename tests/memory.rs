@@ -0,0 +1,120 @@
+#[allow(dead_code)]
+mod common;
+
+use common::{test_decode_serialized_truncated, test_encode_decode};
+use uds_rw::UdsMessage;
+
+#[test]
+fn read_memory_by_address_req_ok() {
+    use uds_rw::message::ReadMemoryByAddressReq;
+    let req = UdsMessage::ReadMemoryByAddressReq(ReadMemoryByAddressReq {
+        memory_size_bytes: 1,
+        memory_address_bytes: 4,
+        memory_address: 0x2048_1392,
+        memory_size: 0x03,
+    });
+    let exp = vec![0x23, 0x14, 0x20, 0x48, 0x13, 0x92, 0x03];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn read_memory_by_address_req_5_bytes_ok() {
+    use uds_rw::message::ReadMemoryByAddressReq;
+    let req = UdsMessage::ReadMemoryByAddressReq(ReadMemoryByAddressReq {
+        memory_size_bytes: 3,
+        memory_address_bytes: 5,
+        memory_address: 0x01_2048_1392,
+        memory_size: 0x01_0000,
+    });
+    let exp = vec![0x23, 0x35, 0x01, 0x20, 0x48, 0x13, 0x92, 0x01, 0x00, 0x00];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn read_memory_by_address_req_serialized_truncated() {
+    let truncated = vec![0x23, 0x14, 0x20, 0x48, 0x13, 0x92];
+    test_decode_serialized_truncated(&truncated);
+}
+
+#[test]
+fn read_memory_by_address_rsp_ok() {
+    use uds_rw::message::ReadMemoryByAddressRsp;
+    let req = UdsMessage::ReadMemoryByAddressRsp(ReadMemoryByAddressRsp {
+        data: vec![0x00, 0x01, 0x8c],
+    });
+    let exp = vec![0x63, 0x00, 0x01, 0x8c];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn write_memory_by_address_req_ok() {
+    use uds_rw::message::WriteMemoryByAddressReq;
+    let req = UdsMessage::WriteMemoryByAddressReq(WriteMemoryByAddressReq {
+        memory_size_bytes: 1,
+        memory_address_bytes: 2,
+        memory_address: 0x2048,
+        memory_size: 0x02,
+        data: vec![0x00, 0x8c],
+    });
+    let exp = vec![0x3d, 0x12, 0x20, 0x48, 0x02, 0x00, 0x8c];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn write_memory_by_address_rsp_ok() {
+    use uds_rw::message::WriteMemoryByAddressRsp;
+    let req = UdsMessage::WriteMemoryByAddressRsp(WriteMemoryByAddressRsp {
+        memory_size_bytes: 1,
+        memory_address_bytes: 2,
+        memory_address: 0x2048,
+        memory_size: 0x02,
+    });
+    let exp = vec![0x7d, 0x12, 0x20, 0x48, 0x02];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn write_memory_by_address_req_invalid() {
+    use uds_rw::message::WriteMemoryByAddressReq;
+    let valid = WriteMemoryByAddressReq {
+        memory_size_bytes: 1,
+        memory_address_bytes: 2,
+        memory_address: 0x2048,
+        memory_size: 0x02,
+        data: vec![0x00, 0x8c],
+    };
+    for req in [
+        WriteMemoryByAddressReq {
+            data: vec![0x00],
+            ..valid.clone()
+        },
+        WriteMemoryByAddressReq {
+            memory_address: 0x1_2048,
+            ..valid.clone()
+        },
+        WriteMemoryByAddressReq {
+            memory_size: 0x100,
+            data: vec![0x00; 0x100],
+            ..valid
+        },
+    ] {
+        let req = UdsMessage::WriteMemoryByAddressReq(req);
+        assert!(uds_rw::uds_write(&mut vec![], &req).is_err());
+    }
+}
+
+#[test]
+fn write_memory_by_address_rsp_trailing_bytes_err() {
+    let data = [0x7d, 0x12, 0x20, 0x48, 0x02, 0x00];
+    assert!(uds_rw::uds_read(&mut &data[..], data.len()).is_err());
+}
+
+#[test]
+fn write_memory_by_address_req_read_invalid() {
+    let data = [0x3d, 0x12, 0x20, 0x48, 0x02, 0x00, 0x8c];
+    assert!(matches!(
+        uds_rw::uds_read(&mut &data[..], 4),
+        Err(uds_rw::UdsError::PayloadLengthTooShort { .. })
+    ));
+    assert!(uds_rw::uds_read(&mut &data[..], data.len() - 1).is_err());
+}
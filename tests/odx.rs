@@ -0,0 +1,280 @@
+#![cfg(feature = "odx")]
+
+use std::io::{Cursor, Write};
+use uds_rw::message::{
+    DTCRspSubfunction, Dtc, DtcAndStatusRecord, GotListDtcAndStatusRecord, ReadDIDRsp, ReadDTCRsp,
+};
+use uds_rw::{DidFieldType, DidValue, OdxDatabase, UdsMessage};
+
+const DATA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ODX xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" MODEL-VERSION="2.2.0">
+  <DIAG-LAYER-CONTAINER ID="DLC_ECU">
+    <SHORT-NAME>ECU</SHORT-NAME>
+    <BASE-VARIANTS>
+      <BASE-VARIANT ID="BV_ECU">
+        <SHORT-NAME>ECU</SHORT-NAME>
+        <DIAG-DATA-DICTIONARY-SPEC>
+          <DTC-DOPS>
+            <DTC-DOP ID="DOP_DTC">
+              <SHORT-NAME>DTCs</SHORT-NAME>
+              <DTCS>
+                <DTC ID="DTC_P0A1B">
+                  <SHORT-NAME>BatteryVoltageLow</SHORT-NAME>
+                  <TROUBLE-CODE>668443</TROUBLE-CODE>
+                  <DISPLAY-TROUBLE-CODE>P0A1B</DISPLAY-TROUBLE-CODE>
+                  <TEXT>Battery voltage below threshold</TEXT>
+                </DTC>
+                <DTC ID="DTC_U0100">
+                  <SHORT-NAME>LostCommunicationEngine</SHORT-NAME>
+                  <TROUBLE-CODE>0xc10000</TROUBLE-CODE>
+                </DTC>
+              </DTCS>
+            </DTC-DOP>
+          </DTC-DOPS>
+          <DATA-OBJECT-PROPS>
+            <DATA-OBJECT-PROP ID="DOP_Speed">
+              <SHORT-NAME>Speed</SHORT-NAME>
+              <COMPU-METHOD>
+                <CATEGORY>LINEAR</CATEGORY>
+                <COMPU-INTERNAL-TO-PHYS>
+                  <COMPU-SCALES>
+                    <COMPU-SCALE>
+                      <COMPU-RATIONAL-COEFFS>
+                        <COMPU-NUMERATOR><V>0</V><V>1</V></COMPU-NUMERATOR>
+                        <COMPU-DENOMINATOR><V>100</V></COMPU-DENOMINATOR>
+                      </COMPU-RATIONAL-COEFFS>
+                    </COMPU-SCALE>
+                  </COMPU-SCALES>
+                </COMPU-INTERNAL-TO-PHYS>
+              </COMPU-METHOD>
+              <DIAG-CODED-TYPE BASE-DATA-TYPE="A_UINT32" xsi:type="STANDARD-LENGTH-TYPE">
+                <BIT-LENGTH>16</BIT-LENGTH>
+              </DIAG-CODED-TYPE>
+              <PHYSICAL-TYPE BASE-DATA-TYPE="A_FLOAT64"/>
+              <UNIT-REF ID-REF="UNIT_kmh"/>
+            </DATA-OBJECT-PROP>
+            <DATA-OBJECT-PROP ID="DOP_Gear">
+              <SHORT-NAME>Gear</SHORT-NAME>
+              <COMPU-METHOD>
+                <CATEGORY>TEXTTABLE</CATEGORY>
+                <COMPU-INTERNAL-TO-PHYS>
+                  <COMPU-SCALES>
+                    <COMPU-SCALE>
+                      <LOWER-LIMIT>0</LOWER-LIMIT>
+                      <COMPU-CONST><VT>Park</VT></COMPU-CONST>
+                    </COMPU-SCALE>
+                    <COMPU-SCALE>
+                      <LOWER-LIMIT>1</LOWER-LIMIT>
+                      <COMPU-CONST><VT>Drive</VT></COMPU-CONST>
+                    </COMPU-SCALE>
+                  </COMPU-SCALES>
+                </COMPU-INTERNAL-TO-PHYS>
+              </COMPU-METHOD>
+              <DIAG-CODED-TYPE BASE-DATA-TYPE="A_UINT32" xsi:type="STANDARD-LENGTH-TYPE">
+                <BIT-LENGTH>8</BIT-LENGTH>
+              </DIAG-CODED-TYPE>
+              <PHYSICAL-TYPE BASE-DATA-TYPE="A_UNICODE2STRING"/>
+            </DATA-OBJECT-PROP>
+          </DATA-OBJECT-PROPS>
+          <UNIT-SPEC>
+            <UNITS>
+              <UNIT ID="UNIT_kmh">
+                <SHORT-NAME>kmh</SHORT-NAME>
+                <DISPLAY-NAME>km/h</DISPLAY-NAME>
+              </UNIT>
+            </UNITS>
+          </UNIT-SPEC>
+        </DIAG-DATA-DICTIONARY-SPEC>
+        <DIAG-COMMS>
+          <DIAG-SERVICE ID="DS_Read_Motion">
+            <SHORT-NAME>Motion</SHORT-NAME>
+            <REQUEST-REF ID-REF="RQ_Read_Motion" DOCREF="ECU" DOCTYPE="LAYER"/>
+            <POS-RESPONSE-REFS>
+              <POS-RESPONSE-REF ID-REF="PR_Read_Motion"/>
+            </POS-RESPONSE-REFS>
+          </DIAG-SERVICE>
+        </DIAG-COMMS>
+        <REQUESTS>
+          <REQUEST ID="RQ_Read_Motion">
+            <SHORT-NAME>RQ_Read_Motion</SHORT-NAME>
+            <PARAMS>
+              <PARAM SEMANTIC="SERVICE-ID" xsi:type="CODED-CONST">
+                <SHORT-NAME>SID</SHORT-NAME>
+                <BYTE-POSITION>0</BYTE-POSITION>
+                <CODED-VALUE>34</CODED-VALUE>
+              </PARAM>
+              <PARAM SEMANTIC="ID" xsi:type="CODED-CONST">
+                <SHORT-NAME>DID</SHORT-NAME>
+                <BYTE-POSITION>1</BYTE-POSITION>
+                <CODED-VALUE>4660</CODED-VALUE>
+              </PARAM>
+            </PARAMS>
+          </REQUEST>
+        </REQUESTS>
+        <POS-RESPONSES>
+          <POS-RESPONSE ID="PR_Read_Motion">
+            <SHORT-NAME>PR_Read_Motion</SHORT-NAME>
+            <PARAMS>
+              <PARAM SEMANTIC="SERVICE-ID" xsi:type="CODED-CONST">
+                <SHORT-NAME>SID</SHORT-NAME>
+                <BYTE-POSITION>0</BYTE-POSITION>
+                <CODED-VALUE>98</CODED-VALUE>
+              </PARAM>
+              <PARAM SEMANTIC="ID" xsi:type="MATCHING-REQUEST-PARAM">
+                <SHORT-NAME>DID</SHORT-NAME>
+                <BYTE-POSITION>1</BYTE-POSITION>
+              </PARAM>
+              <PARAM SEMANTIC="DATA" xsi:type="VALUE">
+                <SHORT-NAME>speed</SHORT-NAME>
+                <BYTE-POSITION>3</BYTE-POSITION>
+                <DOP-REF ID-REF="DOP_Speed"/>
+              </PARAM>
+              <PARAM SEMANTIC="DATA" xsi:type="VALUE">
+                <SHORT-NAME>gear</SHORT-NAME>
+                <BYTE-POSITION>5</BYTE-POSITION>
+                <DOP-REF ID-REF="DOP_Gear"/>
+              </PARAM>
+            </PARAMS>
+          </POS-RESPONSE>
+        </POS-RESPONSES>
+      </BASE-VARIANT>
+    </BASE-VARIANTS>
+  </DIAG-LAYER-CONTAINER>
+</ODX>
+"#;
+
+#[test]
+fn odx_did_layout() {
+    let database = OdxDatabase::from_odx(DATA).unwrap();
+    let layout = database.dids.get(0x1234).unwrap();
+    assert_eq!(layout.name, "Motion");
+    assert_eq!(layout.length(), 3);
+    assert_eq!(
+        layout.fields[0].field_type,
+        DidFieldType::Unsigned {
+            scale: 0.01,
+            offset: 0.0,
+            unit: Some("km/h".to_string())
+        }
+    );
+    let values = database.dids.decode(0x1234, &[0x13, 0x88, 0x01]).unwrap();
+    assert_eq!(
+        values[1],
+        (
+            "gear".to_string(),
+            DidValue::Enum {
+                raw: 1,
+                label: Some("Drive".to_string())
+            }
+        )
+    );
+}
+
+#[test]
+fn odx_integer_too_long() {
+    let data = DATA.replace("<BIT-LENGTH>16</BIT-LENGTH>", "<BIT-LENGTH>72</BIT-LENGTH>");
+    let database = OdxDatabase::from_odx(&data).unwrap();
+    assert!(database.dids.get(0x1234).is_none());
+}
+
+#[test]
+fn odx_dtc_definitions() {
+    let database = OdxDatabase::from_odx(DATA).unwrap();
+    let definition = database.dtcs.get(&Dtc::new(0x0a, 0x33, 0x1b)).unwrap();
+    assert_eq!(definition.name, "BatteryVoltageLow");
+    assert_eq!(definition.display_code.as_deref(), Some("P0A1B"));
+    assert_eq!(
+        definition.text.as_deref(),
+        Some("Battery voltage below threshold")
+    );
+    assert!(database.dtcs.get(&Dtc::new(0xc1, 0x00, 0x00)).is_some());
+}
+
+#[test]
+fn odx_symbolic_display() {
+    let database = OdxDatabase::from_odx(DATA).unwrap();
+    let rsp = UdsMessage::ReadDIDRsp(ReadDIDRsp {
+        did: 0x1234,
+        user_data: vec![0x13, 0x88, 0x00],
+        other_records: vec![],
+    });
+    assert_eq!(
+        database.display(&rsp).to_string(),
+        "ReadDIDRsp(Motion(speed=50 km/h, gear=Park (0x00)))"
+    );
+
+    let rsp = UdsMessage::ReadDTCRsp(ReadDTCRsp {
+        sub: DTCRspSubfunction::ResponseDTCByStatusMask(GotListDtcAndStatusRecord {
+            availability_mask: 0xff.into(),
+            dtcs: vec![
+                DtcAndStatusRecord {
+                    dtc: Dtc::new(0x0a, 0x33, 0x1b),
+                    status: 0x09.into(),
+                },
+                DtcAndStatusRecord {
+                    dtc: Dtc::new(0x01, 0x02, 0x03),
+                    status: 0x08.into(),
+                },
+            ],
+        }),
+    });
+    assert_eq!(
+        database.display(&rsp).to_string(),
+        "ReadDTCRsp::ResponseDTCByStatusMask: avail_mask=0xff :\n\
+         \t  1. dtc P0A1B (BatteryVoltageLow) status=0x09 [testFailed, confirmedDTC]\n\
+         \t  2. dtc 0x010203 status=0x08 [confirmedDTC]"
+    );
+}
+
+#[test]
+fn pdx_archive() {
+    let mut pdx = Cursor::new(vec![]);
+    {
+        let mut writer = zip::ZipWriter::new(&mut pdx);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        writer.start_file("index.xml", options).unwrap();
+        writer.write_all(b"<CATALOG/>").unwrap();
+        writer.start_file("ECU.odx-d", options).unwrap();
+        writer.write_all(DATA.as_bytes()).unwrap();
+        writer.finish().unwrap();
+    }
+    let database = OdxDatabase::from_pdx(pdx).unwrap();
+    assert_eq!(database, OdxDatabase::from_odx(DATA).unwrap());
+}
+
+#[test]
+fn pdx_archive_ids_per_file() {
+    // Same ID as in DATA, which must not shadow the DOP of the ECU layer
+    let other = r#"<ODX><DIAG-LAYER-CONTAINER ID="DLC_Other">
+      <SHORT-NAME>Other</SHORT-NAME>
+      <DATA-OBJECT-PROP ID="DOP_Speed"><SHORT-NAME>Speed</SHORT-NAME>
+        <DIAG-CODED-TYPE BASE-DATA-TYPE="A_UINT32"><BIT-LENGTH>8</BIT-LENGTH></DIAG-CODED-TYPE>
+      </DATA-OBJECT-PROP>
+    </DIAG-LAYER-CONTAINER></ODX>"#;
+    let mut pdx = Cursor::new(vec![]);
+    {
+        let mut writer = zip::ZipWriter::new(&mut pdx);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        writer.start_file("ECU.odx-d", options).unwrap();
+        writer.write_all(DATA.as_bytes()).unwrap();
+        writer.start_file("Other.odx-c", options).unwrap();
+        writer.write_all(other.as_bytes()).unwrap();
+        writer.finish().unwrap();
+    }
+    let database = OdxDatabase::from_pdx(pdx).unwrap();
+    assert_eq!(database, OdxDatabase::from_odx(DATA).unwrap());
+}
+
+#[test]
+fn odx_duplicate_id() {
+    let odx = DATA.replace(r#"ID="DOP_Gear""#, r#"ID="DOP_Speed""#);
+    assert!(OdxDatabase::from_odx(&odx).is_err());
+}
+
+#[test]
+fn odx_invalid() {
+    assert!(OdxDatabase::from_odx("<ODX>").is_err());
+    assert!(OdxDatabase::from_pdx(Cursor::new(vec![0u8; 8])).is_err());
+}
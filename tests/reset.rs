@@ -0,0 +1,64 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use uds_rw::UdsMessage;
+
+#[test]
+fn ecu_reset_req_ok() {
+    use uds_rw::message::{EcuResetReq, ResetType};
+    let req = UdsMessage::EcuResetReq(EcuResetReq {
+        reset_type: ResetType::HardReset,
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x11, 0x01];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn ecu_reset_req_suppress_ok() {
+    use uds_rw::message::{EcuResetReq, ResetType};
+    let req = UdsMessage::EcuResetReq(EcuResetReq {
+        reset_type: ResetType::SoftReset,
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x11, 0x83];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn ecu_reset_rsp_ok() {
+    use uds_rw::message::{EcuResetRsp, ResetType};
+    let req = UdsMessage::EcuResetRsp(EcuResetRsp {
+        reset_type: ResetType::SystemSupplierSpecific(0x61),
+        power_down_time: None,
+    });
+    let exp = vec![0x51, 0x61];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn ecu_reset_rsp_power_down_time_ok() {
+    use uds_rw::message::{EcuResetRsp, ResetType};
+    let req = UdsMessage::EcuResetRsp(EcuResetRsp {
+        reset_type: ResetType::EnableRapidPowerShutDown,
+        power_down_time: Some(0x0a),
+    });
+    let exp = vec![0x51, 0x04, 0x0a];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn ecu_reset_rsp_power_down_time_mismatch_err() {
+    use uds_rw::message::{EcuResetRsp, ResetType};
+    for (reset_type, power_down_time) in [
+        (ResetType::HardReset, Some(0x0a)),
+        (ResetType::EnableRapidPowerShutDown, None),
+    ] {
+        let rsp = UdsMessage::EcuResetRsp(EcuResetRsp {
+            reset_type,
+            power_down_time,
+        });
+        assert!(uds_rw::uds_write(&mut vec![], &rsp).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use uds_rw::UdsMessage;
+
+#[test]
+fn routine_control_req_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::RoutineControlReq(RoutineControlReq {
+        control_type: RoutineControlType::StartRoutine,
+        routine_identifier: ROUTINE_ERASE_MEMORY,
+        option_record: vec![0x44, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00],
+        suppress_positive_response: false,
+    });
+    let exp = vec![
+        0x31, 0x01, 0xff, 0x00, 0x44, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00,
+    ];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn routine_control_req_stop_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::RoutineControlReq(RoutineControlReq {
+        control_type: RoutineControlType::StopRoutine,
+        routine_identifier: 0x1234,
+        option_record: vec![],
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x31, 0x82, 0x12, 0x34];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn routine_control_rsp_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::RoutineControlRsp(RoutineControlRsp {
+        control_type: RoutineControlType::RequestRoutineResults,
+        routine_identifier: ROUTINE_CHECK_PROGRAMMING_DEPENDENCIES,
+        routine_info: Some(0x00),
+        status_record: vec![0x01, 0x02],
+    });
+    let exp = vec![0x71, 0x03, 0xff, 0x01, 0x00, 0x01, 0x02];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn routine_control_rsp_no_info_ok() {
+    use uds_rw::message::*;
+    let req = UdsMessage::RoutineControlRsp(RoutineControlRsp {
+        control_type: RoutineControlType::StartRoutine,
+        routine_identifier: ROUTINE_CHECK_MEMORY,
+        routine_info: None,
+        status_record: vec![],
+    });
+    let exp = vec![0x71, 0x01, 0x02, 0x02];
+    test_encode_decode(&req, &exp);
+}
@@ -0,0 +1,95 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use uds_rw::UdsMessage;
+
+#[test]
+fn security_access_req_request_seed_ok() {
+    use uds_rw::message::{SecurityAccessReq, SecurityAccessRequest};
+    let req = UdsMessage::SecurityAccessReq(SecurityAccessReq {
+        level: 1,
+        request: SecurityAccessRequest::RequestSeed(vec![]),
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x27, 0x01];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn security_access_req_request_seed_data_record_ok() {
+    use uds_rw::message::{SecurityAccessReq, SecurityAccessRequest};
+    let req = UdsMessage::SecurityAccessReq(SecurityAccessReq {
+        level: 0x11,
+        request: SecurityAccessRequest::RequestSeed(vec![0xaa, 0xbb]),
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x27, 0x21, 0xaa, 0xbb];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn security_access_req_send_key_ok() {
+    use uds_rw::message::{SecurityAccessReq, SecurityAccessRequest};
+    let req = UdsMessage::SecurityAccessReq(SecurityAccessReq {
+        level: 2,
+        request: SecurityAccessRequest::SendKey(vec![0x01, 0x02, 0x03, 0x04]),
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x27, 0x84, 0x01, 0x02, 0x03, 0x04];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn security_access_rsp_seed_ok() {
+    use uds_rw::message::{SecurityAccessResponse, SecurityAccessRsp};
+    let req = UdsMessage::SecurityAccessRsp(SecurityAccessRsp {
+        level: 1,
+        response: SecurityAccessResponse::Seed(vec![0x12, 0x34, 0x56, 0x78]),
+    });
+    let exp = vec![0x67, 0x01, 0x12, 0x34, 0x56, 0x78];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn security_access_rsp_key_accepted_ok() {
+    use uds_rw::message::{SecurityAccessResponse, SecurityAccessRsp};
+    let req = UdsMessage::SecurityAccessRsp(SecurityAccessRsp {
+        level: 1,
+        response: SecurityAccessResponse::KeyAccepted,
+    });
+    let exp = vec![0x67, 0x02];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn security_access_key_algorithm() {
+    use uds_rw::message::{
+        SecurityAccessRequest, SecurityAccessResponse, SecurityAccessRsp, SecurityKeyAlgorithm,
+    };
+    use uds_rw::UdsError;
+
+    struct AddLevel;
+    impl SecurityKeyAlgorithm for AddLevel {
+        fn compute_key(&self, level: u8, seed: &[u8]) -> Result<Vec<u8>, UdsError> {
+            Ok(seed.iter().map(|b| b.wrapping_add(level)).collect())
+        }
+    }
+
+    let rsp = SecurityAccessRsp {
+        level: 3,
+        response: SecurityAccessResponse::Seed(vec![0x10, 0xff]),
+    };
+    let req = rsp.key_request(&AddLevel).unwrap();
+    assert_eq!(req.level, 3);
+    assert_eq!(
+        req.request,
+        SecurityAccessRequest::SendKey(vec![0x13, 0x02])
+    );
+
+    let rsp = SecurityAccessRsp {
+        level: 3,
+        response: SecurityAccessResponse::KeyAccepted,
+    };
+    assert!(rsp.key_request(&AddLevel).is_err());
+}
@@ -0,0 +1,84 @@
+#[allow(dead_code)]
+mod common;
+
+use common::test_encode_decode;
+use std::time::Duration;
+use uds_rw::UdsMessage;
+
+#[test]
+fn diagnostic_session_control_req_ok() {
+    use uds_rw::message::{DiagnosticSessionControlReq, DiagnosticSessionType};
+    let req = UdsMessage::DiagnosticSessionControlReq(DiagnosticSessionControlReq {
+        session_type: DiagnosticSessionType::ProgrammingSession,
+        suppress_positive_response: false,
+    });
+    let exp = vec![0x10, 0x02];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn diagnostic_session_control_req_suppress_ok() {
+    use uds_rw::message::{DiagnosticSessionControlReq, DiagnosticSessionType};
+    let req = UdsMessage::DiagnosticSessionControlReq(DiagnosticSessionControlReq {
+        session_type: DiagnosticSessionType::VehicleManufacturerSpecific(0x41),
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x10, 0xc1];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn diagnostic_session_control_rsp_ok() {
+    use uds_rw::message::{DiagnosticSessionControlRsp, DiagnosticSessionType};
+    let req = UdsMessage::DiagnosticSessionControlRsp(DiagnosticSessionControlRsp {
+        session_type: DiagnosticSessionType::ExtendedDiagnosticSession,
+        p2_server_max: Duration::from_millis(50),
+        p2_star_server_max: Duration::from_millis(5000),
+    });
+    let exp = vec![0x50, 0x03, 0x00, 0x32, 0x01, 0xf4];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn tester_present_req_ok() {
+    use uds_rw::message::TesterPresentReq;
+    let req = UdsMessage::TesterPresentReq(TesterPresentReq {
+        suppress_positive_response: true,
+    });
+    let exp = vec![0x3e, 0x80];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn tester_present_rsp_ok() {
+    use uds_rw::message::TesterPresentRsp;
+    let req = UdsMessage::TesterPresentRsp(TesterPresentRsp {});
+    let exp = vec![0x7e, 0x00];
+    test_encode_decode(&req, &exp);
+}
+
+#[test]
+fn tester_present_keep_alive() {
+    use std::time::Instant;
+    use uds_rw::TesterPresentKeepAlive;
+
+    let mut frames: Vec<Vec<u8>> = vec![];
+    let start = Instant::now();
+    {
+        let mut keep_alive =
+            TesterPresentKeepAlive::new(Duration::from_secs(2), |frame: &[u8]| {
+                frames.push(frame.to_vec());
+                Ok(())
+            });
+        let after = |ms| start + Duration::from_millis(ms);
+        assert!(keep_alive.poll_at(start).unwrap());
+        assert!(!keep_alive.poll_at(after(1999)).unwrap());
+        assert!(keep_alive.poll_at(after(2000)).unwrap());
+        assert!(!keep_alive.poll_at(after(3000)).unwrap());
+        assert!(keep_alive.poll_at(after(5000)).unwrap());
+        keep_alive.reset_at(after(6000));
+        assert!(!keep_alive.poll_at(after(7999)).unwrap());
+        assert!(keep_alive.poll_at(after(8000)).unwrap());
+    }
+    assert_eq!(frames, vec![vec![0x3e, 0x80]; 4]);
+}
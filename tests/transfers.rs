@@ -1,3 +1,4 @@
+#[allow(dead_code)]
 mod common;
 
 use common::{test_decode_serialized_truncated, test_encode_decode};
@@ -93,6 +94,42 @@ fn request_file_transfer_rsp_truncated() {
     test_decode_serialized_truncated(&truncated);
 }
 
+#[test]
+fn request_upload_req_ok() {
+    use uds_rw::message::RequestUploadReq;
+    let req = UdsMessage::RequestUploadReq(RequestUploadReq {
+        compression_method: 0x00,
+        encryption_method: 0x00,
+        memory_size_bytes: 2,
+        memory_address_bytes: 4,
+        memory_address: 0x2000_0000,
+        memory_size: 0x0400,
+    });
+    let exp = vec![0x35, 0x00, 0x24, 0x20, 0x00, 0x00, 0x00, 0x04, 0x00];
+    test_encode_decode(&req, &exp);
+    assert_eq!(
+        req.to_string(),
+        "RequestUploadReq(compress=0x0,_encrypt=0x0, address=0x20000000, size=0x400)"
+    );
+}
+
+#[test]
+fn request_upload_req_serialized_truncated() {
+    let truncated = vec![0x35, 0x00, 0x24, 0x20, 0x00, 0x00, 0x00, 0x04];
+    test_decode_serialized_truncated(&truncated);
+}
+
+#[test]
+fn request_upload_rsp_ok() {
+    use uds_rw::message::RequestUploadRsp;
+    let req = UdsMessage::RequestUploadRsp(RequestUploadRsp {
+        max_block_size_bytes: 2,
+        max_block_size: 0x0102,
+    });
+    let exp = vec![0x75, 0x20, 0x01, 0x02];
+    test_encode_decode(&req, &exp);
+}
+
 #[test]
 fn transfer_data_req_ok() {
     use uds_rw::message::TransferDataReq;
@@ -109,11 +146,23 @@ fn transfer_data_rsp_ok() {
     use uds_rw::message::TransferDataRsp;
     let req = UdsMessage::TransferDataRsp(TransferDataRsp {
         block_sequence_counter: 4,
+        data: vec![],
     });
     let exp = vec![0x76, 0x04];
     test_encode_decode(&req, &exp);
 }
 
+#[test]
+fn transfer_data_rsp_upload_ok() {
+    use uds_rw::message::TransferDataRsp;
+    let req = UdsMessage::TransferDataRsp(TransferDataRsp {
+        block_sequence_counter: 1,
+        data: vec![0xde, 0xad],
+    });
+    let exp = vec![0x76, 0x01, 0xde, 0xad];
+    test_encode_decode(&req, &exp);
+}
+
 #[test]
 fn transfer_exit_req_ok() {
     use uds_rw::message::TransferExitReq;